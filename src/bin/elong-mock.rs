@@ -0,0 +1,71 @@
+//! 本地模拟艺龙接口
+//!
+//! ```text
+//! elong-mock [--addr 127.0.0.1:8080] [--fixtures DIR] [--account USER:APP_KEY:APP_SECRET]...
+//!            [--latency-ms N] [--max-clock-skew-secs N] [--gzip] [--orders]
+//! ```
+
+use std::{env, net::SocketAddr, process, sync::Arc, time::Duration};
+
+use elong_sdk::{
+    elong::credentials::Credentials,
+    mock::{orders::OrderSimulator, server::MockServer},
+};
+
+const USAGE: &str = "usage: elong-mock [--addr 127.0.0.1:8080] [--fixtures DIR] \
+[--account USER:APP_KEY:APP_SECRET]... [--latency-ms N] [--max-clock-skew-secs N] [--gzip] [--orders]";
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() {
+    let mut builder = MockServer::builder().addr(SocketAddr::from(([127, 0, 0, 1], 8080)));
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .unwrap_or_else(|| exit(&format!("{arg} needs a value")))
+        };
+        builder = match arg.as_str() {
+            "--addr" => builder.addr(value().parse().unwrap_or_else(|_| exit("invalid --addr"))),
+            "--fixtures" => builder.fixture_dir(value()),
+            "--account" => {
+                let value = value();
+                let mut parts = value.splitn(3, ':');
+                match (parts.next(), parts.next(), parts.next()) {
+                    (Some(user), Some(app_key), Some(app_secret)) => {
+                        builder.account(Credentials::new(user, app_key, app_secret))
+                    }
+                    _ => exit("--account expects USER:APP_KEY:APP_SECRET"),
+                }
+            }
+            "--latency-ms" => builder.latency(Duration::from_millis(number(&value()))),
+            "--max-clock-skew-secs" => {
+                builder.max_clock_skew(Duration::from_secs(number(&value())))
+            }
+            "--gzip" => builder.gzip(true),
+            "--orders" => builder.order_simulator(Arc::new(OrderSimulator::new())),
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return;
+            }
+            _ => exit(&format!("unknown argument {arg}")),
+        };
+    }
+
+    let server = builder
+        .start()
+        .await
+        .unwrap_or_else(|e| exit(&e.to_string()));
+    println!("elong mock server listening on {}", server.url());
+    server.run().await;
+}
+
+fn number(value: &str) -> u64 {
+    value
+        .parse()
+        .unwrap_or_else(|_| exit(&format!("invalid number {value}")))
+}
+
+fn exit(message: &str) -> ! {
+    eprintln!("{message}\n{USAGE}");
+    process::exit(2)
+}
@@ -0,0 +1,187 @@
+use std::sync::Arc;
+
+use tokio::runtime::{Builder, Runtime};
+
+use crate::{
+    request::{
+        api_request::ApiCall, data_booking::DataBookingRequest, data_inventory::InventoryRequest,
+        data_rate::DataRateRequest, data_rp::DataRpRequest, data_validate::DataValidateRequest,
+        dictionary::DictionaryRequest, exchangerate::ExchangerateRequest,
+        hotel_detail_request::HotelDetailRequest, hotel_rate_min_request::HotelRateMinRequest,
+        incr_id::IncrIdRequest, incr_inv::IncrInvRequest, incr_order::IncrOrderRequest,
+        incr_rate::IncrRateRequest, incr_state::IncrStateRequest,
+        order_addinvoice::OrderAddinvoiceRequest, order_cancel::OrderCancelRequest,
+        order_create::OrderCreateRequest, order_detail::OrderDetailRequest,
+        order_feedback::OrderFeedbackRequest, order_list::OrderListRequest,
+        order_pay::OrderPayRequest, order_pay_confirm::OrderPayConfirmRequest,
+        order_promote::OrderPromoteRequest, order_related::OrderRelatedRequest,
+        static_brand::StaticBrandRequest, static_city::StaticCityRequest,
+        static_grade::StaticGradeRequest, static_group::StaticGroupRequest,
+        static_info::StaticInfoRequest, static_list::StaticListRequest,
+    },
+    response::api_response::{BaseResponse, ElongResponse},
+    types::*,
+    Elong,
+};
+
+use super::{builder::ElongServiceBuilder, error::ElongError, service::ElongService};
+
+/// 同步版本的 `ElongService`
+///
+/// 方法与 [`Elong`] trait 一一对应，内部持有一个单线程 tokio 运行时执行异步调用，
+/// 适用于批处理工具、构建脚本等同步代码。不能在异步上下文中调用，否则会 panic。
+#[derive(Clone)]
+pub struct ElongBlockingService {
+    inner: ElongService,
+    runtime: Arc<Runtime>,
+}
+
+impl ElongBlockingService {
+    /// 构建器，通过 [`ElongServiceBuilder::build_blocking`] 创建
+    pub fn builder() -> ElongServiceBuilder {
+        ElongServiceBuilder::new()
+    }
+
+    /// 包装已有的异步服务
+    pub fn from_service(service: ElongService) -> Result<Self, ElongError> {
+        let runtime = Builder::new_current_thread().enable_all().build()?;
+        Ok(ElongBlockingService {
+            inner: service,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// 使用指定账户调用的服务视图，见 [`ElongService::with_account`]
+    pub fn with_account(&self, account: impl Into<String>) -> Self {
+        ElongBlockingService {
+            inner: self.inner.with_account(account),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// 使用指定语言调用的服务视图，见 [`ElongService::with_local`]
+    pub fn with_local(&self, local: impl Into<String>) -> Self {
+        ElongBlockingService {
+            inner: self.inner.with_local(local),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// 使用指定版本调用的服务视图，见 [`ElongService::with_version`]
+    pub fn with_version(&self, version: impl Into<String>) -> Self {
+        ElongBlockingService {
+            inner: self.inner.with_version(version),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// 内部的异步服务
+    pub fn inner(&self) -> &ElongService {
+        &self.inner
+    }
+
+    /// 调用接口，见 [`ElongService::call`]
+    pub fn call<R>(&self, request: R) -> Result<ElongResponse<R::Response>, ElongError>
+    where
+        R: ApiCall,
+        ElongResponse<R::Response>: BaseResponse,
+    {
+        self.runtime.block_on(self.inner.call(request))
+    }
+
+    /// 调用任意接口，见 [`ElongService::call_raw`]
+    pub fn call_raw(
+        &self,
+        method_name: &str,
+        request: serde_json::Value,
+    ) -> Result<ElongResponse<serde_json::Value>, ElongError> {
+        self.runtime
+            .block_on(self.inner.call_raw(method_name, request))
+    }
+}
+
+macro_rules! blocking_methods {
+    ($($(#[$doc:meta])* fn $name:ident($req:ty) -> $resp:ty;)*) => {
+        impl ElongBlockingService {
+            $(
+                $(#[$doc])*
+                pub fn $name(&self, req: $req) -> $resp {
+                    self.runtime.block_on(self.inner.$name(req))
+                }
+            )*
+        }
+    };
+}
+
+blocking_methods! {
+    /// 城市列表
+    fn get_static_city(StaticCityRequest) -> RECityResp;
+    /// 酒店列表
+    fn get_static_list(StaticListRequest) -> REListResp;
+    /// 酒店详情
+    fn get_static_info(StaticInfoRequest) -> REInfoResp;
+    /// 点评评分
+    fn get_static_grade(StaticGradeRequest) -> REGradeResp;
+    /// 酒店品牌
+    fn get_static_brand(StaticBrandRequest) -> REBrandResp;
+    /// 酒店集团
+    fn get_static_group(StaticGroupRequest) -> REGroupResp;
+    /// 酒店字典
+    fn get_hotel_dictionary(DictionaryRequest) -> REDictionaryResp;
+    /// 产品详情
+    fn get_data_rp(DataRpRequest) -> REDataRpResp;
+    /// 状态增量
+    fn get_incr_state(IncrStateRequest) -> REIncrStateResp;
+    /// 库存全量
+    fn get_inventory(InventoryRequest) -> REInvResp;
+    /// 库存增量
+    fn get_incr_inv(IncrInvRequest) -> REIncrInvResp;
+    /// 库存增量分片
+    fn get_incr_sharding_inv(IncrInvRequest) -> REIncrInvResp;
+    /// 价格全量
+    fn get_data_rate(DataRateRequest) -> RERateResp;
+    /// 价格增量
+    fn get_incr_rate(IncrRateRequest) -> REIncrRateResp;
+    /// 价格增量分片
+    fn get_incr_sharding_rate(IncrRateRequest) -> REIncrRateResp;
+    /// 增量编号
+    fn get_incr_id(IncrIdRequest) -> REIncrIdResp;
+    /// 增量编号分片
+    fn get_incr_sharding_id(IncrIdRequest) -> REIncrIdResp;
+    /// 状态增量分片
+    fn get_incr_sharding_state(IncrStateRequest) -> REIncrStateResp;
+    /// 数据验证
+    fn data_validate(DataValidateRequest) -> REDataValidateResp;
+    /// 预订数据
+    fn data_booking(DataBookingRequest) -> REDataBookingResp;
+    /// 创建订单
+    fn order_create(OrderCreateRequest) -> REOrderCreateResp;
+    /// 订单支付
+    fn order_pay(OrderPayRequest) -> REOrderPayResp;
+    /// 订单支付确认
+    fn order_pay_confirm(OrderPayConfirmRequest) -> REOrderPayConfirmResp;
+    /// 订单增量
+    fn order_incr(IncrOrderRequest) -> REIncrOrderResp;
+    /// 订单详情
+    fn order_detail(OrderDetailRequest) -> REOrderDetailResp;
+    /// 取消订单
+    fn order_cancel(OrderCancelRequest) -> REOrderCancelResp;
+    /// 订单催确认
+    fn order_promote(OrderPromoteRequest) -> REOrderPromoteResp;
+    /// 关联订单
+    fn order_related(OrderRelatedRequest) -> REOrderRelatedResp;
+    /// 入住反馈
+    fn order_feedback(OrderFeedbackRequest) -> REOrderFeedbackResp;
+    /// 补开发票
+    fn order_addinvoice(OrderAddinvoiceRequest) -> REOrderAddinvoiceResp;
+    /// 订单列表
+    fn order_list(OrderListRequest) -> REOrderListResp;
+    /// 汇率
+    fn exchangerate(ExchangerateRequest) -> REExchangeRateResp;
+    /// 酒店详情搜索
+    fn hotel_detail(HotelDetailRequest) -> REHotelDetailResp;
+    /// 酒店详情搜索
+    fn hotel_crawl_detail(HotelDetailRequest) -> REHotelDetailResp;
+    /// 酒店最小价
+    fn hotel_rate_min(HotelRateMinRequest) -> REHotelRateMinResp;
+}
@@ -0,0 +1,309 @@
+use std::{env, path::PathBuf, sync::Arc, time::Duration};
+
+use crate::network::{
+    cassette::CassetteTransport,
+    http_client::{HttpClient, HttpClientConfig},
+    transport::Transport,
+};
+
+use super::{
+    client::{ElongClient, DEFAULT_LOCAL, DEFAULT_VERSION},
+    clock::Clock,
+    credentials::{CredentialProvider, Credentials, StaticCredentials},
+    endpoints::{ApiEndpoint, ApiMethod},
+    error::ElongError,
+    metrics::MetricsRecorder,
+    middleware::Middleware,
+    rate_limit::RateLimitConfig,
+    retry::RetryPolicy,
+    secret::Secret,
+    service::ElongService,
+};
+
+type TransportWrapper = Box<dyn FnOnce(Arc<dyn Transport>) -> Arc<dyn Transport> + Send>;
+
+/// `ElongService` 构建器
+///
+/// 未设置账户信息或账户信息来源时回退到环境变量 `ELONG_USERNAME`、`ELONG_APP_KEY`、
+/// `ELONG_APP_SECRET`，未设置的接口地址会回退到 `ApiEndpoint::Prod`。
+#[derive(Default)]
+pub struct ElongServiceBuilder {
+    username: Option<String>,
+    app_key: Option<Secret>,
+    app_secret: Option<Secret>,
+    credentials_provider: Option<Arc<dyn CredentialProvider>>,
+    url: Option<String>,
+    http: HttpClientConfig,
+    transport: Option<Arc<dyn Transport>>,
+    cassette_dir: Option<PathBuf>,
+    transport_wrappers: Vec<TransportWrapper>,
+    retry: RetryPolicy,
+    rate_limit: RateLimitConfig,
+    log_payloads: bool,
+    clock: Option<Arc<dyn Clock>>,
+    version: Option<String>,
+    method_versions: Vec<(ApiMethod, String)>,
+    local: Option<String>,
+    post_threshold: Option<Option<usize>>,
+    post_methods: Vec<ApiMethod>,
+    middlewares: Vec<Arc<dyn Middleware>>,
+    metrics: Option<Arc<dyn MetricsRecorder>>,
+}
+
+impl ElongServiceBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 账户名、appKey、appSecret
+    pub fn credentials(
+        mut self,
+        username: impl Into<String>,
+        app_key: impl Into<Secret>,
+        app_secret: impl Into<Secret>,
+    ) -> Self {
+        self.username = Some(username.into());
+        self.app_key = Some(app_key.into());
+        self.app_secret = Some(app_secret.into());
+        self
+    }
+
+    /// 账户信息来源，设置后 [`Self::credentials`] 不再生效
+    ///
+    /// 每次签名前读取，可用于多账户与密钥轮换，见 [`super::credentials`]。
+    pub fn credentials_provider(mut self, provider: Arc<dyn CredentialProvider>) -> Self {
+        self.credentials_provider = Some(provider);
+        self
+    }
+
+    /// 使用预置的接口环境
+    pub fn endpoint(mut self, endpoint: ApiEndpoint) -> Self {
+        self.url = Some(endpoint.url());
+        self
+    }
+
+    /// 使用任意接口地址，例如代理网关或本地模拟服务
+    pub fn base_url(mut self, url: impl Into<String>) -> Self {
+        self.url = Some(url.into());
+        self
+    }
+
+    /// 整体请求超时，默认 30 秒
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.http.timeout = timeout;
+        self
+    }
+
+    /// 建立连接超时
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.http.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// 读取超时
+    pub fn read_timeout(mut self, timeout: Duration) -> Self {
+        self.http.read_timeout = Some(timeout);
+        self
+    }
+
+    /// 代理地址，例如 `http://127.0.0.1:8080`
+    pub fn proxy(mut self, proxy: impl Into<String>) -> Self {
+        self.http.proxy = Some(proxy.into());
+        self
+    }
+
+    /// 每个主机的最大空闲连接数，默认 100
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.http.pool_max_idle_per_host = max;
+        self
+    }
+
+    /// 空闲连接的保活时间
+    pub fn pool_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.http.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// User-Agent
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.http.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// 响应体（解压后）的最大字节数，默认 64MB，`None` 表示不限制
+    pub fn max_response_size(mut self, max: Option<usize>) -> Self {
+        self.http.max_response_size = max;
+        self
+    }
+
+    /// 追加默认请求头
+    pub fn default_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.http.default_headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// 使用自定义传输层
+    ///
+    /// 超时、代理、连接池、User-Agent、默认请求头与响应体上限只作用于内置的 HTTP 客户端，
+    /// 与自定义传输层同时设置时 [`build`](Self::build) 返回 [`ElongError::ConfigError`]。
+    pub fn transport(mut self, transport: Arc<dyn Transport>) -> Self {
+        self.transport = Some(transport);
+        self
+    }
+
+    /// 重试策略，默认查询接口最多尝试 3 次
+    pub fn retry_policy(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// 按接口限流，同一服务的所有克隆共享配额
+    pub fn rate_limit(mut self, rate_limit: RateLimitConfig) -> Self {
+        self.rate_limit = rate_limit;
+        self
+    }
+
+    /// 在 debug 日志中记录脱敏后的请求与响应内容，默认关闭
+    pub fn log_payloads(mut self, enabled: bool) -> Self {
+        self.log_payloads = enabled;
+        self
+    }
+
+    /// 签名时钟，默认使用系统时间
+    pub fn clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+
+    /// 接口版本，如 `"1.62"`，默认 1.62
+    pub fn version(mut self, version: impl Into<String>) -> Self {
+        self.version = Some(version.into());
+        self
+    }
+
+    /// 为指定接口固定版本
+    pub fn method_version(mut self, method: ApiMethod, version: impl Into<String>) -> Self {
+        self.method_versions.push((method, version.into()));
+        self
+    }
+
+    /// 语言，默认 `zh-CN`，传 `en-US` 时返回英文内容
+    pub fn local(mut self, local: impl Into<String>) -> Self {
+        self.local = Some(local.into());
+        self
+    }
+
+    /// 签名参数长度超过该值时改用 POST，默认 4096，`None` 表示不按长度切换
+    pub fn post_threshold(mut self, threshold: Option<usize>) -> Self {
+        self.post_threshold = Some(threshold);
+        self
+    }
+
+    /// 指定接口总是使用 POST
+    pub fn post_method(mut self, method: ApiMethod) -> Self {
+        self.post_methods.push(method);
+        self
+    }
+
+    /// 把请求与响应录制到目录中，供 [`CassetteTransport::replay`] 回放
+    pub fn record_cassette(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.cassette_dir = Some(dir.into());
+        self
+    }
+
+    /// 包装最终使用的传输层，如 [`crate::network::fault::FaultTransport`]，先注册的在内层
+    pub fn wrap_transport(
+        mut self,
+        wrapper: impl FnOnce(Arc<dyn Transport>) -> Arc<dyn Transport> + Send + 'static,
+    ) -> Self {
+        self.transport_wrappers.push(Box::new(wrapper));
+        self
+    }
+
+    /// 注册中间件，先注册的在外层，可观察、修改或直接返回响应
+    pub fn middleware(mut self, middleware: Arc<dyn Middleware>) -> Self {
+        self.middlewares.push(middleware);
+        self
+    }
+
+    /// 指标记录，可使用 [`super::metrics::InMemoryMetrics`]
+    pub fn metrics(mut self, metrics: Arc<dyn MetricsRecorder>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    pub fn build(self) -> Result<ElongService, ElongError> {
+        let credentials: Arc<dyn CredentialProvider> = match self.credentials_provider {
+            Some(provider) => provider,
+            None => Arc::new(StaticCredentials::new().default_account(Credentials::new(
+                credential(self.username, "ELONG_USERNAME")?,
+                secret(self.app_key, "ELONG_APP_KEY")?,
+                secret(self.app_secret, "ELONG_APP_SECRET")?,
+            ))),
+        };
+
+        let mut transport = match self.transport {
+            Some(_) if self.http != HttpClientConfig::default() => {
+                return Err(ElongError::ConfigError(
+                    "HTTP client settings cannot be combined with a custom transport".to_string(),
+                ));
+            }
+            Some(transport) => transport,
+            None => Arc::new(HttpClient::build(self.http)?),
+        };
+        if let Some(dir) = self.cassette_dir {
+            transport = Arc::new(CassetteTransport::record(dir, transport));
+        }
+        for wrapper in self.transport_wrappers {
+            transport = wrapper(transport);
+        }
+        let mut client = ElongClient::with_credentials_provider(credentials, transport)
+            .with_retry_policy(self.retry)
+            .with_rate_limit(self.rate_limit)
+            .with_payload_logging(self.log_payloads)
+            .with_version(self.version.unwrap_or_else(|| DEFAULT_VERSION.to_string()))
+            .with_local(self.local.unwrap_or_else(|| DEFAULT_LOCAL.to_string()));
+        for (method, version) in self.method_versions {
+            client = client.with_method_version(method, version);
+        }
+        if let Some(threshold) = self.post_threshold {
+            client = client.with_post_threshold(threshold);
+        }
+        for method in self.post_methods {
+            client = client.with_post_method(method);
+        }
+        for middleware in self.middlewares {
+            client = client.with_middleware(middleware);
+        }
+        if let Some(metrics) = self.metrics {
+            client = client.with_metrics(metrics);
+        }
+        if let Some(clock) = self.clock {
+            client = client.with_clock(clock);
+        }
+        let url = self.url.unwrap_or_else(|| ApiEndpoint::Prod.url());
+
+        Ok(ElongService { client, url })
+    }
+
+    /// 构建同步服务
+    #[cfg(feature = "blocking")]
+    pub fn build_blocking(self) -> Result<super::blocking::ElongBlockingService, ElongError> {
+        super::blocking::ElongBlockingService::from_service(self.build()?)
+    }
+}
+
+fn credential(value: Option<String>, var: &str) -> Result<String, ElongError> {
+    match value {
+        Some(value) => Ok(value),
+        None => env::var(var)
+            .map_err(|_| ElongError::ConfigError(format!("Environment variable {var} is not set"))),
+    }
+}
+
+fn secret(value: Option<Secret>, var: &str) -> Result<Secret, ElongError> {
+    match value {
+        Some(value) => Ok(value),
+        None => credential(None, var).map(Secret::new),
+    }
+}
@@ -1,70 +1,460 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{
+        atomic::{AtomicI64, Ordering},
+        Arc,
+    },
+    time::Instant,
+};
+
+use chrono::{DateTime, Utc};
+
 use crate::{
     elong::error::ElongError,
-    network::http_client::HttpClient,
-    request::api_request::{ApiRequestPayload, ApiSignedRequest, BaseRequest},
-    response::api_response::BaseResponse,
+    network::{
+        http_client::{HttpClient, HttpClientConfig},
+        transport::{HttpMethod, Transport},
+    },
+    request::api_request::{ApiCall, ApiRequestPayload, ApiSignedRequest, BaseRequest},
+    response::api_response::{BaseResponse, ElongResponse},
 };
 
-use super::endpoints::ApiMethod;
+use super::{
+    clock::{Clock, SystemClock},
+    credentials::{CredentialProvider, Credentials, StaticCredentials},
+    endpoints::ApiMethod,
+    error::ApiError,
+    metrics::{CallMetrics, CallOutcome, MetricsRecorder},
+    middleware::{Middleware, MiddlewareRequest, Next},
+    rate_limit::{RateLimitConfig, RateLimiter},
+    redact::{redact_bytes, redact_json},
+    retry::RetryPolicy,
+    trace::CallSpan,
+};
+
+/// 默认接口版本
+pub const DEFAULT_VERSION: &str = "1.62";
+/// 默认语言
+pub const DEFAULT_LOCAL: &str = "zh-CN";
+/// 默认改用 POST 的参数长度，常见网关的 URL 长度限制在 4KB 到 8KB 之间
+pub const DEFAULT_POST_THRESHOLD: usize = 4096;
 
 #[derive(Clone)]
 pub struct ElongClient {
-    /// HTTP 请求客户端
-    client: HttpClient,
-    /// 账户名
-    username: String,
-    /// appKey
-    app_key: String,
-    /// appSecret
-    app_secret: String,
+    /// 传输层
+    transport: Arc<dyn Transport>,
+    /// 账户信息来源，每次签名前读取
+    credentials: Arc<dyn CredentialProvider>,
+    /// 使用的账户，`None` 为默认账户
+    account: Option<String>,
+    /// 重试策略
+    retry: RetryPolicy,
+    /// 限流器，克隆间共享
+    rate_limiter: RateLimiter,
+    /// 是否记录请求与响应内容（已脱敏）
+    log_payloads: bool,
+    /// 签名时钟
+    clock: Arc<dyn Clock>,
+    /// 服务器时间与本地时钟的偏差（秒），克隆间共享
+    clock_offset: Arc<AtomicI64>,
+    /// 接口版本
+    version: String,
+    /// 按接口固定的版本
+    method_versions: HashMap<ApiMethod, String>,
+    /// 语言，`zh-CN` 或 `en-US`
+    local: String,
+    /// 参数长度超过该值时改用 POST
+    post_threshold: Option<usize>,
+    /// 总是使用 POST 的接口
+    post_methods: HashSet<ApiMethod>,
+    /// 中间件，按注册顺序由外向内执行
+    middlewares: Vec<Arc<dyn Middleware>>,
+    /// 指标记录
+    metrics: Option<Arc<dyn MetricsRecorder>>,
+}
+
+/// 一次调用在多次尝试间累计的统计
+#[derive(Default)]
+struct CallStats {
+    /// 发出的请求数
+    attempts: u32,
+    /// 最后一次响应的 HTTP 状态码
+    http_status: Option<u16>,
+    /// 请求字节数
+    request_bytes: usize,
+    /// 响应体字节数
+    response_bytes: usize,
 }
 
 impl ElongClient {
+    /// 使用默认 HTTP 配置创建客户端
+    ///
+    /// # Panics
+    ///
+    /// 无法创建 HTTP 客户端时 panic，例如 TLS 后端初始化失败。
+    #[deprecated(
+        note = "use `ElongService::builder()`, which returns an error instead of panicking"
+    )]
     pub fn new(username: String, app_key: String, app_secret: String) -> Self {
-        let client = HttpClient::new();
+        let transport =
+            HttpClient::build(HttpClientConfig::default()).expect("Failed to create HTTP client");
+        Self::with_transport(username, app_key, app_secret, Arc::new(transport))
+    }
+
+    /// 使用自定义传输层创建客户端
+    pub fn with_transport(
+        username: String,
+        app_key: String,
+        app_secret: String,
+        transport: Arc<dyn Transport>,
+    ) -> Self {
+        let credentials = StaticCredentials::new()
+            .default_account(Credentials::new(username, app_key, app_secret));
+        Self::with_credentials_provider(Arc::new(credentials), transport)
+    }
+
+    /// 使用账户信息来源创建客户端，支持多账户与密钥轮换
+    pub fn with_credentials_provider(
+        credentials: Arc<dyn CredentialProvider>,
+        transport: Arc<dyn Transport>,
+    ) -> Self {
         ElongClient {
-            client,
-            username,
-            app_key,
-            app_secret,
+            transport,
+            credentials,
+            account: None,
+            retry: RetryPolicy::default(),
+            rate_limiter: RateLimiter::default(),
+            log_payloads: false,
+            clock: Arc::new(SystemClock),
+            clock_offset: Arc::new(AtomicI64::new(0)),
+            version: DEFAULT_VERSION.to_string(),
+            method_versions: HashMap::new(),
+            local: DEFAULT_LOCAL.to_string(),
+            post_threshold: Some(DEFAULT_POST_THRESHOLD),
+            post_methods: HashSet::new(),
+            middlewares: Vec::new(),
+            metrics: None,
+        }
+    }
+
+    /// 使用指定账户，`None` 为默认账户
+    ///
+    /// 克隆后切换账户的客户端共享连接池、限流与时钟校准。
+    pub fn with_account(mut self, account: Option<String>) -> Self {
+        self.account = account;
+        self
+    }
+
+    /// 设置重试策略
+    pub fn with_retry_policy(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// 设置限流配置，请求会等待配额而不是被艺龙拒绝
+    pub fn with_rate_limit(mut self, config: RateLimitConfig) -> Self {
+        self.rate_limiter = RateLimiter::new(config);
+        self
+    }
+
+    /// 是否在 debug 日志中记录请求与响应内容
+    ///
+    /// 默认关闭；开启后联系人、入住人、信用卡等字段会先脱敏再写入日志。
+    pub fn with_payload_logging(mut self, enabled: bool) -> Self {
+        self.log_payloads = enabled;
+        self
+    }
+
+    /// 设置签名时钟
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// 设置接口版本，如 `"1.62"`，默认 1.62，通过 [`Self::with_method_version`] 固定的接口除外
+    pub fn with_version(mut self, version: impl Into<String>) -> Self {
+        self.version = version.into();
+        self
+    }
+
+    /// 为指定接口固定版本，用于字段随版本变化的接口
+    pub fn with_method_version(mut self, method: ApiMethod, version: impl Into<String>) -> Self {
+        self.method_versions.insert(method, version.into());
+        self
+    }
+
+    /// 所有接口统一使用指定版本，忽略按接口固定的版本
+    pub(crate) fn override_version(mut self, version: String) -> Self {
+        self.version = version;
+        self.method_versions.clear();
+        self
+    }
+
+    /// 设置语言，默认 `zh-CN`，传 `en-US` 时返回英文内容
+    pub fn with_local(mut self, local: impl Into<String>) -> Self {
+        self.local = local.into();
+        self
+    }
+
+    /// 签名参数长度超过 `threshold` 时改用 POST，`None` 表示不按长度切换
+    ///
+    /// 默认 4096，避免大订单或多国籍查询超过网关的 URL 长度限制。
+    pub fn with_post_threshold(mut self, threshold: Option<usize>) -> Self {
+        self.post_threshold = threshold;
+        self
+    }
+
+    /// 指定接口总是使用 POST
+    pub fn with_post_method(mut self, method: ApiMethod) -> Self {
+        self.post_methods.insert(method);
+        self
+    }
+
+    /// 追加中间件，先追加的在外层
+    pub fn with_middleware(mut self, middleware: Arc<dyn Middleware>) -> Self {
+        self.middlewares.push(middleware);
+        self
+    }
+
+    /// 设置指标记录，每次调用结束后回调一次
+    pub fn with_metrics(mut self, metrics: Arc<dyn MetricsRecorder>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// 请求方式
+    fn http_method(&self, method: ApiMethod, signed: &ApiSignedRequest) -> HttpMethod {
+        let too_long = self
+            .post_threshold
+            .is_some_and(|threshold| signed.to_params().len() > threshold);
+        if too_long || self.post_methods.contains(&method) {
+            HttpMethod::Post
+        } else {
+            HttpMethod::Get
+        }
+    }
+
+    /// 指定接口使用的版本
+    pub fn version_for(&self, method: ApiMethod) -> &str {
+        self.method_versions.get(&method).unwrap_or(&self.version)
+    }
+
+    /// 当前语言
+    pub fn local(&self) -> &str {
+        &self.local
+    }
+
+    /// 当前使用的时钟偏差（秒），由服务器 `Date` 响应头校准
+    pub fn clock_offset(&self) -> i64 {
+        self.clock_offset.load(Ordering::Relaxed)
+    }
+
+    /// 签名使用的时间戳
+    fn timestamp(&self) -> i64 {
+        self.clock.now().timestamp() + self.clock_offset()
+    }
+
+    /// 按服务器时间校准时钟偏差，偏差有变化时返回 `true`
+    fn correct_clock(&self, server_time: DateTime<Utc>) -> bool {
+        let offset = server_time.timestamp() - self.clock.now().timestamp();
+        let previous = self.clock_offset.swap(offset, Ordering::Relaxed);
+        if offset == previous {
+            return false;
         }
+        log::warn!("local clock is off by {offset}s from the Elong server, adjusting signatures");
+        true
+    }
+
+    /// 调用接口，接口名与响应类型由请求类型决定
+    pub async fn call<R>(
+        &self,
+        url: &str,
+        request: R,
+    ) -> Result<ElongResponse<R::Response>, ElongError>
+    where
+        R: ApiCall,
+        ElongResponse<R::Response>: BaseResponse,
+    {
+        self.fetch_data(url, R::METHOD, request).await
+    }
+
+    /// 调用任意接口，请求与响应均为 JSON
+    ///
+    /// 用于调用 SDK 尚未建模的接口，例如 `hotel.list`、`hotel.sr.create`，
+    /// 签名、解压、重试与错误处理与其他接口一致。
+    pub async fn call_raw(
+        &self,
+        url: &str,
+        method_name: &str,
+        request: serde_json::Value,
+    ) -> Result<ElongResponse<serde_json::Value>, ElongError> {
+        self.fetch_data(url, ApiMethod::from_name(method_name)?, request)
+            .await
     }
 
     /// 获取数据
+    ///
+    /// 艺龙返回的 `Code` 不为 `"0"` 时返回 [`ElongError::ApiError`]。
+    /// 遇到网络故障、5xx 或艺龙系统繁忙时按重试策略自动重试，每次重试都会重新签名。
+    /// 艺龙返回时间戳或签名错误时，按响应头 `Date` 校准时钟偏差后立即重试一次。
     pub async fn fetch_data<T, U>(
         &self,
         url: &str,
         method: ApiMethod,
         request: T,
-    ) -> Result<U, ElongError>
+    ) -> Result<ElongResponse<U>, ElongError>
     where
         T: BaseRequest,
-        U: BaseResponse,
+        ElongResponse<U>: BaseResponse,
+    {
+        let started = Instant::now();
+        let span = CallSpan::new(method);
+        let mut stats = CallStats::default();
+        // 序列化失败同样计入调用结果
+        let result = match request.to_json() {
+            Ok(request) => {
+                span.record_request_size(request.len());
+                span.instrument(self.execute(url, method, &request, &mut stats))
+                    .await
+            }
+            Err(err) => Err(err),
+        };
+        let elapsed = started.elapsed();
+
+        let retries = stats.attempts.saturating_sub(1);
+        span.record_result(&result, stats.http_status, retries, elapsed);
+        if let Some(metrics) = &self.metrics {
+            metrics.record(&CallMetrics {
+                method,
+                outcome: CallOutcome::of(&result),
+                elapsed,
+                attempts: stats.attempts,
+                request_bytes: stats.request_bytes,
+                response_bytes: stats.response_bytes,
+            });
+        }
+        result
+    }
+
+    /// 按重试策略发送请求
+    async fn execute<U>(
+        &self,
+        url: &str,
+        method: ApiMethod,
+        request: &str,
+        stats: &mut CallStats,
+    ) -> Result<ElongResponse<U>, ElongError>
+    where
+        ElongResponse<U>: BaseResponse,
+    {
+        let max_attempts = if self.retry.is_enabled_for(method) {
+            self.retry.max_attempts
+        } else {
+            1
+        };
+
+        let mut attempt = 1;
+        let mut clock_corrected = false;
+        loop {
+            let (result, server_time) = self.send(url, method, request, stats).await;
+            // 签名被拒绝时请求未被处理，校准时钟后重试对非幂等接口同样安全
+            if let (Err(err), Some(server_time)) = (&result, server_time) {
+                if !clock_corrected && err.is_signature_error() && self.correct_clock(server_time) {
+                    clock_corrected = true;
+                    continue;
+                }
+            }
+
+            let retryable = matches!(&result, Err(err) if err.is_retryable());
+            if !retryable || attempt >= max_attempts {
+                return result;
+            }
+
+            let backoff = self.retry.backoff_for(attempt);
+            log::warn!(
+                "{} attempt {attempt} failed, retrying in {backoff:?}",
+                method.name()
+            );
+            tokio::time::sleep(backoff).await;
+            attempt += 1;
+        }
+    }
+
+    /// 签名并发送一次请求，同时返回响应中的服务器时间
+    async fn send<U>(
+        &self,
+        url: &str,
+        method: ApiMethod,
+        request: &str,
+        stats: &mut CallStats,
+    ) -> (Result<ElongResponse<U>, ElongError>, Option<DateTime<Utc>>)
+    where
+        ElongResponse<U>: BaseResponse,
     {
-        let request = request.to_json()?;
+        let credentials = match self.credentials.credentials(self.account.as_deref()) {
+            Ok(credentials) => credentials,
+            Err(err) => return (Err(err), None),
+        };
+        // 艺龙按账户分配配额，不同账户分别限流
+        self.rate_limiter
+            .acquire(&credentials.username, method)
+            .await;
 
         let data = ApiRequestPayload {
-            version: 1.62,
-            local: "zh-CN".to_string(),
-            request,
+            version: self.version_for(method).to_string(),
+            local: self.local.clone(),
+            request: request.to_string(),
         };
 
-        let params = ApiSignedRequest::new(
-            self.username.clone(),
-            self.app_key.clone(),
-            self.app_secret.clone(),
+        let signed = match ApiSignedRequest::new_at(
+            credentials.username.clone(),
+            credentials.app_key.expose(),
+            credentials.app_secret.expose(),
             method.name().to_owned(),
             data,
-        )
-        .to_params();
+            self.timestamp(),
+        ) {
+            Ok(signed) => signed,
+            Err(err) => return (Err(err), None),
+        };
 
-        let url = format!("{url}?{params}");
-        log::debug!("url: {url}");
+        if self.log_payloads {
+            log::debug!("{} request: {}", method.name(), redact_json(request));
+        }
 
-        let response = self.client.get(&url).await?;
+        let http_method = self.http_method(method, &signed);
+        let mut call = MiddlewareRequest::new(method, url, http_method, signed, request);
+        let next = Next::new(self.transport.as_ref(), &self.middlewares);
+        let response = next.run(&mut call).await;
+        stats.attempts += 1;
+        stats.request_bytes += request.len();
+        let response = match response {
+            Ok(response) => response,
+            Err(err) => {
+                if let ElongError::HttpStatus(status) = err {
+                    stats.http_status = Some(status);
+                }
+                return (Err(err), None);
+            }
+        };
+        stats.http_status = response.status;
+        stats.response_bytes += response.body.len();
 
-        let result = U::from_json(response)?;
+        if self.log_payloads {
+            log::debug!(
+                "{} response: {}",
+                method.name(),
+                redact_bytes(&response.body)
+            );
+        }
 
-        Ok(result)
+        let result = ElongResponse::<U>::from_slice(&response.body).and_then(|response| {
+            if response.is_success() {
+                Ok(response)
+            } else {
+                Err(ApiError::new(&response.code, response.guid, method.name()).into())
+            }
+        });
+        (result, response.server_time)
     }
 }
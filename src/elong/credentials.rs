@@ -0,0 +1,258 @@
+use std::{
+    collections::HashMap,
+    env, fs,
+    path::PathBuf,
+    sync::{Arc, RwLock, Weak},
+    time::{Duration, SystemTime},
+};
+
+use serde::Deserialize;
+use zeroize::Zeroizing;
+
+use super::{error::ElongError, secret::Secret};
+
+/// 艺龙账户信息
+///
+/// appKey 与 appSecret 保存为 [`Secret`]，`Debug` 时隐藏、释放时清零。
+/// 提供方以 `Arc` 返回，签名时只借用，不会在每次请求时复制密钥。
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Credentials {
+    /// 账户名
+    pub username: String,
+    /// appKey
+    pub app_key: Secret,
+    /// appSecret
+    pub app_secret: Secret,
+}
+
+impl Credentials {
+    pub fn new(
+        username: impl Into<String>,
+        app_key: impl Into<Secret>,
+        app_secret: impl Into<Secret>,
+    ) -> Self {
+        Credentials {
+            username: username.into(),
+            app_key: app_key.into(),
+            app_secret: app_secret.into(),
+        }
+    }
+}
+
+/// 账户信息来源
+///
+/// 每次签名前都会调用，实现可以随时返回新的密钥，无需重建服务。
+/// `account` 为 `None` 时返回默认账户，否则返回指定名称的账户，
+/// 多个渠道或租户可以共用一个 `ElongService` 与连接池。
+pub trait CredentialProvider: Send + Sync {
+    fn credentials(&self, account: Option<&str>) -> Result<Arc<Credentials>, ElongError>;
+}
+
+fn unknown_account(account: &str) -> ElongError {
+    ElongError::ConfigError(format!("Unknown Elong account {account}"))
+}
+
+/// 固定的多账户配置
+#[derive(Debug, Clone, Default)]
+pub struct StaticCredentials {
+    default: Option<Arc<Credentials>>,
+    accounts: HashMap<String, Arc<Credentials>>,
+}
+
+impl StaticCredentials {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 默认账户
+    pub fn default_account(mut self, credentials: Credentials) -> Self {
+        self.default = Some(Arc::new(credentials));
+        self
+    }
+
+    /// 添加命名账户
+    pub fn account(mut self, name: impl Into<String>, credentials: Credentials) -> Self {
+        self.accounts.insert(name.into(), Arc::new(credentials));
+        self
+    }
+}
+
+impl CredentialProvider for StaticCredentials {
+    fn credentials(&self, account: Option<&str>) -> Result<Arc<Credentials>, ElongError> {
+        match account {
+            None => self
+                .default
+                .clone()
+                .ok_or_else(|| ElongError::ConfigError("No default Elong account".to_string())),
+            Some(account) => self
+                .accounts
+                .get(account)
+                .cloned()
+                .ok_or_else(|| unknown_account(account)),
+        }
+    }
+}
+
+/// 从环境变量读取
+///
+/// 默认账户读取 `ELONG_USERNAME`、`ELONG_APP_KEY`、`ELONG_APP_SECRET`；
+/// 命名账户 `channel_a` 读取 `ELONG_CHANNEL_A_USERNAME` 等变量。
+/// 每个账户只在首次使用时读取一次，环境变量变化后需调用 [`EnvCredentials::reload`]。
+#[derive(Debug, Default)]
+pub struct EnvCredentials {
+    cache: RwLock<HashMap<String, Arc<Credentials>>>,
+}
+
+impl EnvCredentials {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 清空缓存，之后的请求重新读取环境变量
+    pub fn reload(&self) {
+        self.cache.write().unwrap().clear();
+    }
+}
+
+impl CredentialProvider for EnvCredentials {
+    fn credentials(&self, account: Option<&str>) -> Result<Arc<Credentials>, ElongError> {
+        let prefix = match account {
+            None => "ELONG_".to_string(),
+            Some(account) => format!("ELONG_{}_", account.to_uppercase()),
+        };
+        if let Some(credentials) = self.cache.read().unwrap().get(&prefix) {
+            return Ok(credentials.clone());
+        }
+        let var = |name: &str| {
+            let var = format!("{prefix}{name}");
+            env::var(&var).map_err(|_| {
+                ElongError::ConfigError(format!("Environment variable {var} is not set"))
+            })
+        };
+        let credentials = Arc::new(Credentials::new(
+            var("USERNAME")?,
+            var("APP_KEY")?,
+            var("APP_SECRET")?,
+        ));
+        self.cache
+            .write()
+            .unwrap()
+            .insert(prefix, credentials.clone());
+        Ok(credentials)
+    }
+}
+
+/// 从 JSON 文件读取
+///
+/// 文件格式：
+///
+/// ```json
+/// {
+///   "default": { "username": "u", "app_key": "k", "app_secret": "s" },
+///   "accounts": {
+///     "channel_a": { "username": "a", "app_key": "k", "app_secret": "s" }
+///   }
+/// }
+/// ```
+///
+/// 请求时只读取内存中的账户信息，不访问文件系统。文件修改后可调用 [`FileCredentials::reload`]，
+/// 或通过 [`FileCredentials::watch`] 在后台每隔 `reload_interval`（默认 5 秒）检查修改时间并自动重新加载。
+/// 重新加载失败时继续使用上一次成功加载的内容。
+pub struct FileCredentials {
+    path: PathBuf,
+    reload_interval: Duration,
+    state: RwLock<FileState>,
+}
+
+struct FileState {
+    credentials: StaticCredentials,
+    modified: Option<SystemTime>,
+}
+
+#[derive(Deserialize)]
+struct CredentialsFile {
+    default: Option<Credentials>,
+    #[serde(default)]
+    accounts: HashMap<String, Credentials>,
+}
+
+impl FileCredentials {
+    /// 加载文件，文件不存在或格式错误时返回错误
+    pub fn new(path: impl Into<PathBuf>) -> Result<Self, ElongError> {
+        let path = path.into();
+        let (credentials, modified) = load(&path)?;
+        Ok(FileCredentials {
+            path,
+            reload_interval: Duration::from_secs(5),
+            state: RwLock::new(FileState {
+                credentials,
+                modified,
+            }),
+        })
+    }
+
+    /// [`FileCredentials::watch`] 检查文件修改的间隔
+    pub fn reload_interval(mut self, interval: Duration) -> Self {
+        self.reload_interval = interval;
+        self
+    }
+
+    /// 文件修改时间变化时重新加载，返回是否重新加载；会阻塞读取文件，不应在异步任务中直接调用
+    pub fn reload(&self) -> Result<bool, ElongError> {
+        let modified = fs::metadata(&self.path).and_then(|m| m.modified()).ok();
+        if modified.is_some() && modified == self.state.read().unwrap().modified {
+            return Ok(false);
+        }
+        let (credentials, modified) = load(&self.path)?;
+        let mut state = self.state.write().unwrap();
+        state.credentials = credentials;
+        state.modified = modified;
+        log::info!("reloaded Elong credentials from {}", self.path.display());
+        Ok(true)
+    }
+
+    /// 启动后台任务定期调用 [`FileCredentials::reload`]，文件读取在阻塞线程池中进行；
+    /// 需要在 Tokio 运行时中调用，提供方释放后任务自动结束
+    pub fn watch(self: &Arc<Self>) -> tokio::task::JoinHandle<()> {
+        let provider: Weak<Self> = Arc::downgrade(self);
+        let interval = self.reload_interval;
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(interval).await;
+                let Some(provider) = provider.upgrade() else {
+                    return;
+                };
+                let path = provider.path.clone();
+                match tokio::task::spawn_blocking(move || provider.reload()).await {
+                    Ok(Err(err)) => log::warn!(
+                        "failed to reload Elong credentials from {}: {err}",
+                        path.display()
+                    ),
+                    Ok(Ok(_)) => {}
+                    Err(_) => return,
+                }
+            }
+        })
+    }
+}
+
+fn load(path: &PathBuf) -> Result<(StaticCredentials, Option<SystemTime>), ElongError> {
+    let modified = fs::metadata(path).and_then(|m| m.modified()).ok();
+    let content = Zeroizing::new(fs::read(path)?);
+    let file: CredentialsFile = serde_json::from_slice(&content)?;
+    let credentials = StaticCredentials {
+        default: file.default.map(Arc::new),
+        accounts: file
+            .accounts
+            .into_iter()
+            .map(|(name, credentials)| (name, Arc::new(credentials)))
+            .collect(),
+    };
+    Ok((credentials, modified))
+}
+
+impl CredentialProvider for FileCredentials {
+    fn credentials(&self, account: Option<&str>) -> Result<Arc<Credentials>, ElongError> {
+        self.state.read().unwrap().credentials.credentials(account)
+    }
+}
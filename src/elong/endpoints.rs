@@ -1,4 +1,13 @@
-use std::env;
+use std::{
+    collections::HashSet,
+    env,
+    sync::{Mutex, OnceLock},
+};
+
+use super::error::ElongError;
+
+/// 进程内最多驻留的自定义接口名数量
+pub const MAX_CUSTOM_METHODS: usize = 1024;
 
 pub enum ApiEndpoint {
     /// 生产环境
@@ -18,6 +27,7 @@ impl ApiEndpoint {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ApiMethod {
     /// hotel.static.city           城市列表-静态信息(json)
     StaticCity,
@@ -93,9 +103,52 @@ pub enum ApiMethod {
     HotelCrawlDetail,
     /// hotel.rate.min         国内酒店-酒店最低价搜索
     HotelRateMin,
+    /// SDK 未内置的接口，值为接口名
+    Custom(&'static str),
 }
 
 impl ApiMethod {
+    /// 所有内置接口
+    pub const ALL: &'static [ApiMethod] = &[
+        ApiMethod::StaticCity,
+        ApiMethod::StaticList,
+        ApiMethod::StaticInfo,
+        ApiMethod::StaticGrade,
+        ApiMethod::StaticBrand,
+        ApiMethod::StaticGroup,
+        ApiMethod::Dictionary,
+        ApiMethod::DataRp,
+        ApiMethod::IncrState,
+        ApiMethod::DataInventory,
+        ApiMethod::IncrInv,
+        ApiMethod::IncrShardingInv,
+        ApiMethod::DataRate,
+        ApiMethod::IncrRate,
+        ApiMethod::ShardingRate,
+        ApiMethod::IncrId,
+        ApiMethod::IncrShardingId,
+        ApiMethod::IncrShardingState,
+        ApiMethod::DataValidate,
+        ApiMethod::DataBooking,
+        ApiMethod::CreditCardValidate,
+        ApiMethod::OrderCreate,
+        ApiMethod::OrderPay,
+        ApiMethod::OrderPayConfirm,
+        ApiMethod::IncrOrder,
+        ApiMethod::OrderDetail,
+        ApiMethod::OrderUpdate,
+        ApiMethod::OrderCancel,
+        ApiMethod::OrderPromote,
+        ApiMethod::OrderRelated,
+        ApiMethod::OrderFeedback,
+        ApiMethod::OrderAddinvoice,
+        ApiMethod::OrderList,
+        ApiMethod::ExchangeRate,
+        ApiMethod::HotelDetail,
+        ApiMethod::HotelCrawlDetail,
+        ApiMethod::HotelRateMin,
+    ];
+
     pub fn name(&self) -> &'static str {
         match self {
             ApiMethod::StaticCity => "hotel.static.city",
@@ -135,6 +188,65 @@ impl ApiMethod {
             ApiMethod::HotelDetail => "hotel.detail",
             ApiMethod::HotelCrawlDetail => "hotel.crawl.detail",
             ApiMethod::HotelRateMin => "hotel.rate.min",
+            ApiMethod::Custom(name) => name,
+        }
+    }
+
+    /// 由内置接口名解析，不分配内存，SDK 未内置的接口返回 `None`
+    pub fn builtin(name: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|method| method.name() == name)
+    }
+
+    /// 由接口名解析，SDK 未内置的接口返回 [`ApiMethod::Custom`]
+    ///
+    /// 自定义接口名会被驻留且不再释放，进程内最多 [`MAX_CUSTOM_METHODS`] 个，超出时返回 `ConfigError`。
+    pub fn from_name(name: &str) -> Result<Self, ElongError> {
+        match Self::builtin(name) {
+            Some(method) => Ok(method),
+            None => intern(name).map(ApiMethod::Custom),
         }
     }
+
+    /// 是否为幂等的查询接口，可安全地自动重试
+    ///
+    /// 下单、支付、取消等写操作重复提交可能产生副作用，默认不重试；
+    /// 自定义接口的语义未知，同样默认不重试。
+    pub fn is_idempotent(&self) -> bool {
+        !matches!(
+            self,
+            ApiMethod::OrderCreate
+                | ApiMethod::OrderPay
+                | ApiMethod::OrderPayConfirm
+                | ApiMethod::OrderUpdate
+                | ApiMethod::OrderCancel
+                | ApiMethod::OrderPromote
+                | ApiMethod::OrderFeedback
+                | ApiMethod::OrderAddinvoice
+                | ApiMethod::CreditCardValidate
+                | ApiMethod::Custom(_)
+        )
+    }
+}
+
+/// 驻留自定义接口名，同一个接口名只分配一次，总数不超过 [`MAX_CUSTOM_METHODS`]
+fn intern(name: &str) -> Result<&'static str, ElongError> {
+    static NAMES: OnceLock<Mutex<HashSet<&'static str>>> = OnceLock::new();
+    let mut names = NAMES
+        .get_or_init(|| Mutex::new(HashSet::new()))
+        .lock()
+        .unwrap();
+    if let Some(name) = names.get(name) {
+        return Ok(name);
+    }
+    if names.len() >= MAX_CUSTOM_METHODS {
+        return Err(ElongError::ConfigError(format!(
+            "Too many custom API methods (max {MAX_CUSTOM_METHODS}), rejected {name}"
+        )));
+    }
+    let name: &'static str = Box::leak(name.to_string().into_boxed_str());
+    names.insert(name);
+    Ok(name)
 }
@@ -1,58 +1,257 @@
-use std::{fmt, io, string::FromUtf8Error};
+use std::{error::Error, fmt, io, string::FromUtf8Error};
 
-use serde::{Deserialize, Serialize};
+use serde::{Serialize, Serializer};
 
-#[derive(Debug, Deserialize, Serialize)]
+use super::result_code::{ElongResultCode, SuggestedAction};
+
+#[derive(Debug)]
 pub enum ElongError {
     /// 网络请求错误
-    HttpError(String),
+    HttpError(reqwest::Error),
+    /// HTTP 状态码错误
+    HttpStatus(u16),
     /// 序列化或反序列化错误
-    SerdeError(String),
+    SerdeError(serde_json::Error),
     /// 艺龙接口错误
-    ApiError(String),
+    ApiError(ApiError),
     /// IO 错误
-    IoError(String),
+    IoError(io::Error),
     /// UTF-8 解码错误
-    Utf8Error(String),
+    Utf8Error(FromUtf8Error),
+    /// 配置错误
+    ConfigError(String),
+    /// 返回成功但缺少 Result，携带请求的 guid
+    MissingResult(Option<String>),
+    /// 响应体超过配置的上限，携带上限字节数
+    ResponseTooLarge(usize),
     /// 其他错误
     Other(String),
 }
 
+/// 艺龙接口返回的业务错误，即 `Code` 不为 `"0"` 的响应
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ApiError {
+    /// 错误码，例如 `H000997`
+    pub code: String,
+    /// 错误信息
+    pub message: String,
+    /// 请求唯一标识，联系艺龙排查问题时需要提供
+    pub guid: Option<String>,
+    /// 接口名，例如 `hotel.order.create`
+    pub method: String,
+}
+
+impl ApiError {
+    /// 由艺龙返回的 `Code` 构造，`Code` 的格式为 `错误码|错误信息`
+    pub fn new(raw_code: &str, guid: Option<String>, method: &str) -> Self {
+        let (code, message) = match raw_code.split_once('|') {
+            Some((code, message)) => (code.trim(), message.trim()),
+            None => (raw_code.trim(), ""),
+        };
+        ApiError {
+            code: code.to_string(),
+            message: message.to_string(),
+            guid,
+            method: method.to_string(),
+        }
+    }
+
+    /// 返回码分类
+    pub fn result_code(&self) -> ElongResultCode {
+        ElongResultCode::from_parts(&self.code, &self.message)
+    }
+
+    /// 是否为系统繁忙等临时故障
+    pub fn is_retryable(&self) -> bool {
+        self.result_code().suggested_action() == Some(SuggestedAction::Retry)
+    }
+
+    /// 是否为账户或权限错误
+    pub fn is_auth_error(&self) -> bool {
+        self.result_code() == ElongResultCode::AuthFailed
+    }
+
+    /// 是否为签名或时间戳错误
+    pub fn is_signature_error(&self) -> bool {
+        matches!(
+            self.result_code(),
+            ElongResultCode::SignatureInvalid | ElongResultCode::TimestampExpired
+        )
+    }
+
+    /// 是否为库存不足或产品不可订
+    pub fn is_inventory_unavailable(&self) -> bool {
+        matches!(
+            self.result_code(),
+            ElongResultCode::InventoryUnavailable | ElongResultCode::ProductUnavailable
+        )
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.method.is_empty() {
+            write!(f, "[{}] ", self.method)?;
+        }
+        write!(f, "{} {}", self.code, self.message)?;
+        if let Some(guid) = &self.guid {
+            write!(f, " (guid: {guid})")?;
+        }
+        Ok(())
+    }
+}
+
+impl ElongError {
+    /// 艺龙业务错误
+    pub fn api_error(&self) -> Option<&ApiError> {
+        match self {
+            ElongError::ApiError(err) => Some(err),
+            _ => None,
+        }
+    }
+
+    /// 是否为临时故障，重试可能成功
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            ElongError::HttpError(err) => {
+                err.is_timeout() || err.is_connect() || err.is_request() || err.is_body()
+            }
+            ElongError::HttpStatus(status) => *status >= 500 || *status == 429,
+            ElongError::IoError(err) => matches!(
+                err.kind(),
+                io::ErrorKind::ConnectionReset
+                    | io::ErrorKind::ConnectionAborted
+                    | io::ErrorKind::NotConnected
+                    | io::ErrorKind::BrokenPipe
+                    | io::ErrorKind::TimedOut
+                    | io::ErrorKind::Interrupted
+                    | io::ErrorKind::UnexpectedEof
+            ),
+            ElongError::ApiError(err) => err.is_retryable(),
+            _ => false,
+        }
+    }
+
+    /// 是否为账户或权限错误
+    pub fn is_auth_error(&self) -> bool {
+        match self {
+            ElongError::HttpStatus(status) => *status == 401 || *status == 403,
+            ElongError::ApiError(err) => err.is_auth_error(),
+            _ => false,
+        }
+    }
+
+    /// 是否为签名或时间戳错误
+    pub fn is_signature_error(&self) -> bool {
+        self.api_error().is_some_and(ApiError::is_signature_error)
+    }
+
+    /// 是否为库存不足或产品不可订
+    pub fn is_inventory_unavailable(&self) -> bool {
+        self.api_error()
+            .is_some_and(ApiError::is_inventory_unavailable)
+    }
+}
+
 impl fmt::Display for ElongError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            ElongError::HttpError(msg) => write!(f, "HTTP Error:{msg}"),
-            ElongError::SerdeError(msg) => write!(f, "Serialization Error:{msg}"),
-            ElongError::ApiError(msg) => write!(f, "API Error:{msg}"),
-            ElongError::IoError(msg) => write!(f, "IO Error:{msg}"),
-            ElongError::Utf8Error(msg) => write!(f, "UTF-8 Error:{msg}"),
+            ElongError::HttpError(err) => write!(f, "HTTP Error:{err}"),
+            ElongError::HttpStatus(status) => {
+                write!(f, "HTTP Error:request failed with status {status}")
+            }
+            ElongError::SerdeError(err) => write!(f, "Serialization Error:{err}"),
+            ElongError::ApiError(err) => write!(f, "API Error:{err}"),
+            ElongError::IoError(err) => write!(f, "IO Error:{err}"),
+            ElongError::Utf8Error(err) => write!(f, "UTF-8 Error:{err}"),
+            ElongError::ConfigError(msg) => write!(f, "Config Error:{msg}"),
+            ElongError::MissingResult(guid) => match guid {
+                Some(guid) => write!(f, "API Error:missing Result (guid: {guid})"),
+                None => write!(f, "API Error:missing Result"),
+            },
+            ElongError::ResponseTooLarge(limit) => {
+                write!(f, "HTTP Error:response body exceeds {limit} bytes")
+            }
             ElongError::Other(msg) => write!(f, "Other Error:{msg}"),
         }
     }
 }
 
-impl std::error::Error for ElongError {}
+/// 序列化为 `{"变体名": 详情}`，便于写入日志或返回给上游
+impl Serialize for ElongError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let name = "ElongError";
+        match self {
+            ElongError::HttpError(err) => {
+                serializer.serialize_newtype_variant(name, 0, "HttpError", &err.to_string())
+            }
+            ElongError::HttpStatus(status) => {
+                serializer.serialize_newtype_variant(name, 1, "HttpStatus", status)
+            }
+            ElongError::SerdeError(err) => {
+                serializer.serialize_newtype_variant(name, 2, "SerdeError", &err.to_string())
+            }
+            ElongError::ApiError(err) => {
+                serializer.serialize_newtype_variant(name, 3, "ApiError", err)
+            }
+            ElongError::IoError(err) => {
+                serializer.serialize_newtype_variant(name, 4, "IoError", &err.to_string())
+            }
+            ElongError::Utf8Error(err) => {
+                serializer.serialize_newtype_variant(name, 5, "Utf8Error", &err.to_string())
+            }
+            ElongError::ConfigError(msg) => {
+                serializer.serialize_newtype_variant(name, 6, "ConfigError", msg)
+            }
+            ElongError::MissingResult(guid) => {
+                serializer.serialize_newtype_variant(name, 7, "MissingResult", guid)
+            }
+            ElongError::ResponseTooLarge(limit) => {
+                serializer.serialize_newtype_variant(name, 8, "ResponseTooLarge", limit)
+            }
+            ElongError::Other(msg) => serializer.serialize_newtype_variant(name, 9, "Other", msg),
+        }
+    }
+}
+
+impl Error for ElongError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ElongError::HttpError(err) => Some(err),
+            ElongError::SerdeError(err) => Some(err),
+            ElongError::IoError(err) => Some(err),
+            ElongError::Utf8Error(err) => Some(err),
+            _ => None,
+        }
+    }
+}
 
 impl From<reqwest::Error> for ElongError {
     fn from(err: reqwest::Error) -> Self {
-        ElongError::HttpError(err.to_string())
+        ElongError::HttpError(err)
     }
 }
 
 impl From<serde_json::Error> for ElongError {
     fn from(err: serde_json::Error) -> Self {
-        ElongError::SerdeError(err.to_string())
+        ElongError::SerdeError(err)
     }
 }
 
 impl From<io::Error> for ElongError {
     fn from(err: io::Error) -> Self {
-        ElongError::IoError(err.to_string())
+        ElongError::IoError(err)
     }
 }
 
 impl From<FromUtf8Error> for ElongError {
     fn from(err: FromUtf8Error) -> Self {
-        ElongError::Utf8Error(err.to_string())
+        ElongError::Utf8Error(err)
+    }
+}
+
+impl From<ApiError> for ElongError {
+    fn from(err: ApiError) -> Self {
+        ElongError::ApiError(err)
     }
 }
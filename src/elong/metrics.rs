@@ -0,0 +1,233 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Mutex,
+    time::Duration,
+};
+
+use serde::Serialize;
+
+use crate::response::api_response::ElongResponse;
+
+use super::{endpoints::ApiMethod, error::ElongError};
+
+/// 指标记录
+///
+/// 每次接口调用结束后（含全部重试）回调一次，可接入 Prometheus 等监控系统；
+/// 也可以直接使用内置的 [`InMemoryMetrics`]。
+pub trait MetricsRecorder: Send + Sync {
+    fn record(&self, metrics: &CallMetrics);
+}
+
+/// 一次接口调用的指标
+#[derive(Debug, Clone)]
+pub struct CallMetrics {
+    /// 接口
+    pub method: ApiMethod,
+    /// 调用结果
+    pub outcome: CallOutcome,
+    /// 总耗时，包含重试与限流等待
+    pub elapsed: Duration,
+    /// 发出的请求数，重试会使其大于 1
+    pub attempts: u32,
+    /// 业务请求 JSON 字节数，多次尝试累计
+    pub request_bytes: usize,
+    /// 响应体字节数（解压后），多次尝试累计
+    pub response_bytes: usize,
+}
+
+/// 调用结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CallOutcome {
+    /// 成功
+    Success,
+    /// 艺龙返回业务错误，携带错误码
+    ApiError(String),
+    /// 网络、HTTP 状态码或解析错误
+    Error,
+}
+
+impl CallOutcome {
+    pub(crate) fn of<U>(result: &Result<ElongResponse<U>, ElongError>) -> Self {
+        match result {
+            Ok(_) => CallOutcome::Success,
+            Err(err) => match err.api_error() {
+                Some(api_error) => CallOutcome::ApiError(api_error.code.clone()),
+                None => CallOutcome::Error,
+            },
+        }
+    }
+
+    /// 用于统计的返回码，成功为 `"0"`，非业务错误为 `None`
+    pub fn code(&self) -> Option<&str> {
+        match self {
+            CallOutcome::Success => Some("0"),
+            CallOutcome::ApiError(code) => Some(code),
+            CallOutcome::Error => None,
+        }
+    }
+}
+
+/// 延迟分桶的上界（毫秒），最后一个桶收集更慢的请求
+const LATENCY_BOUNDS_MS: &[u64] = &[10, 25, 50, 100, 250, 500, 1000, 2500, 5000, 10000];
+
+/// 内存中的指标统计，适合直接暴露在健康检查接口中
+///
+/// ```ignore
+/// let metrics = Arc::new(InMemoryMetrics::new());
+/// let service = ElongService::builder().metrics(metrics.clone()).build()?;
+/// // ...
+/// let snapshot = serde_json::to_string(&metrics.snapshot())?;
+/// ```
+#[derive(Debug, Default)]
+pub struct InMemoryMetrics {
+    methods: Mutex<HashMap<ApiMethod, MethodMetrics>>,
+}
+
+/// 指标快照，按接口名排序
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct MetricsSnapshot {
+    pub methods: BTreeMap<&'static str, MethodMetrics>,
+}
+
+/// 单个接口的累计指标
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct MethodMetrics {
+    /// 调用次数
+    pub calls: u64,
+    /// 成功次数
+    pub successes: u64,
+    /// 艺龙业务错误次数
+    pub api_errors: u64,
+    /// 网络、HTTP 状态码或解析错误次数
+    pub errors: u64,
+    /// 重试次数
+    pub retries: u64,
+    /// 请求字节数
+    pub request_bytes: u64,
+    /// 响应字节数
+    pub response_bytes: u64,
+    /// 各返回码出现次数
+    pub codes: BTreeMap<String, u64>,
+    /// 延迟分布
+    pub latency: LatencyHistogram,
+}
+
+impl MethodMetrics {
+    /// 失败率
+    pub fn error_rate(&self) -> f64 {
+        if self.calls == 0 {
+            return 0.0;
+        }
+        (self.api_errors + self.errors) as f64 / self.calls as f64
+    }
+}
+
+/// 延迟直方图
+#[derive(Debug, Clone, Serialize)]
+pub struct LatencyHistogram {
+    /// 各桶上界（毫秒）
+    pub bounds_ms: &'static [u64],
+    /// 各桶计数，比 `bounds_ms` 多一个桶用于收集超出上界的请求
+    pub counts: Vec<u64>,
+    /// 总耗时（毫秒）
+    pub sum_ms: u64,
+    /// 最大耗时（毫秒）
+    pub max_ms: u64,
+}
+
+impl Default for LatencyHistogram {
+    fn default() -> Self {
+        LatencyHistogram {
+            bounds_ms: LATENCY_BOUNDS_MS,
+            counts: vec![0; LATENCY_BOUNDS_MS.len() + 1],
+            sum_ms: 0,
+            max_ms: 0,
+        }
+    }
+}
+
+impl LatencyHistogram {
+    fn observe(&mut self, elapsed: Duration) {
+        let ms = elapsed.as_millis() as u64;
+        let bucket = self
+            .bounds_ms
+            .iter()
+            .position(|bound| ms <= *bound)
+            .unwrap_or(self.bounds_ms.len());
+        self.counts[bucket] += 1;
+        self.sum_ms += ms;
+        self.max_ms = self.max_ms.max(ms);
+    }
+
+    /// 样本数
+    pub fn count(&self) -> u64 {
+        self.counts.iter().sum()
+    }
+
+    /// 平均耗时（毫秒）
+    pub fn mean_ms(&self) -> f64 {
+        match self.count() {
+            0 => 0.0,
+            count => self.sum_ms as f64 / count as f64,
+        }
+    }
+
+    /// 分位数的估计值（毫秒），返回所在桶的上界，落在最后一个桶时返回最大耗时
+    pub fn quantile_ms(&self, quantile: f64) -> u64 {
+        let count = self.count();
+        if count == 0 {
+            return 0;
+        }
+        let target = ((count as f64) * quantile.clamp(0.0, 1.0)).ceil().max(1.0) as u64;
+        let mut seen = 0;
+        for (i, bucket) in self.counts.iter().enumerate() {
+            seen += bucket;
+            if seen >= target {
+                return self.bounds_ms.get(i).copied().unwrap_or(self.max_ms);
+            }
+        }
+        self.max_ms
+    }
+}
+
+impl InMemoryMetrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 当前指标的快照
+    pub fn snapshot(&self) -> MetricsSnapshot {
+        let methods = self.methods.lock().unwrap();
+        MetricsSnapshot {
+            methods: methods
+                .iter()
+                .map(|(method, metrics)| (method.name(), metrics.clone()))
+                .collect(),
+        }
+    }
+
+    /// 清空统计
+    pub fn reset(&self) {
+        self.methods.lock().unwrap().clear();
+    }
+}
+
+impl MetricsRecorder for InMemoryMetrics {
+    fn record(&self, call: &CallMetrics) {
+        let mut methods = self.methods.lock().unwrap();
+        let metrics = methods.entry(call.method).or_default();
+        metrics.calls += 1;
+        match &call.outcome {
+            CallOutcome::Success => metrics.successes += 1,
+            CallOutcome::ApiError(_) => metrics.api_errors += 1,
+            CallOutcome::Error => metrics.errors += 1,
+        }
+        if let Some(code) = call.outcome.code() {
+            *metrics.codes.entry(code.to_string()).or_default() += 1;
+        }
+        metrics.retries += u64::from(call.attempts.saturating_sub(1));
+        metrics.request_bytes += call.request_bytes as u64;
+        metrics.response_bytes += call.response_bytes as u64;
+        metrics.latency.observe(call.elapsed);
+    }
+}
@@ -0,0 +1,136 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::{
+    network::transport::{HttpMethod, Transport, TransportResponse},
+    request::api_request::ApiSignedRequest,
+};
+
+use super::{endpoints::ApiMethod, error::ElongError};
+
+/// 中间件
+///
+/// 包裹每一次 HTTP 请求（重试时每次尝试都会经过），按注册顺序由外向内执行。
+/// 调用 `next.run(request)` 继续执行后续中间件与传输层；
+/// 不调用则直接以返回值作为响应，可用于缓存或故障注入。
+///
+/// ```ignore
+/// struct Audit;
+///
+/// #[async_trait]
+/// impl Middleware for Audit {
+///     async fn handle(
+///         &self,
+///         request: &mut MiddlewareRequest,
+///         next: Next<'_>,
+///     ) -> Result<TransportResponse, ElongError> {
+///         request.add_header("X-Trace-Id", "abc");
+///         let result = next.run(request).await;
+///         log::info!("{} ok: {}", request.method.name(), result.is_ok());
+///         result
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait Middleware: Send + Sync {
+    async fn handle(
+        &self,
+        request: &mut MiddlewareRequest,
+        next: Next<'_>,
+    ) -> Result<TransportResponse, ElongError>;
+}
+
+/// 中间件看到的请求
+pub struct MiddlewareRequest {
+    /// 接口
+    pub method: ApiMethod,
+    /// 接口地址
+    pub url: String,
+    /// 请求方式
+    pub http_method: HttpMethod,
+    /// 额外的请求头，需要传输层支持 [`Transport::send_with_headers`]
+    pub headers: Vec<(String, String)>,
+    /// 已签名的请求参数
+    pub signed: ApiSignedRequest,
+    /// 序列化后的业务请求 JSON，即签名数据中的 `Request`
+    request_json: String,
+}
+
+impl MiddlewareRequest {
+    pub(crate) fn new(
+        method: ApiMethod,
+        url: &str,
+        http_method: HttpMethod,
+        signed: ApiSignedRequest,
+        request_json: &str,
+    ) -> Self {
+        MiddlewareRequest {
+            method,
+            url: url.to_string(),
+            http_method,
+            headers: Vec::new(),
+            signed,
+            request_json: request_json.to_string(),
+        }
+    }
+
+    /// 业务请求 JSON
+    ///
+    /// 修改请求内容需要重新签名，因此这里只读。
+    pub fn request_json(&self) -> &str {
+        &self.request_json
+    }
+
+    /// 追加请求头
+    pub fn add_header(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        self.headers.push((name.into(), value.into()));
+    }
+
+    /// 完整的签名参数，GET 时即查询字符串
+    pub fn params(&self) -> String {
+        self.signed.to_params()
+    }
+}
+
+/// 中间件链中剩余的部分
+pub struct Next<'a> {
+    transport: &'a dyn Transport,
+    middlewares: &'a [Arc<dyn Middleware>],
+}
+
+impl<'a> Next<'a> {
+    pub(crate) fn new(
+        transport: &'a dyn Transport,
+        middlewares: &'a [Arc<dyn Middleware>],
+    ) -> Self {
+        Next {
+            transport,
+            middlewares,
+        }
+    }
+
+    /// 执行后续中间件，最后交给传输层发送
+    pub async fn run(
+        self,
+        request: &mut MiddlewareRequest,
+    ) -> Result<TransportResponse, ElongError> {
+        match self.middlewares.split_first() {
+            Some((middleware, rest)) => {
+                middleware
+                    .handle(request, Next::new(self.transport, rest))
+                    .await
+            }
+            None => {
+                self.transport
+                    .send_with_headers(
+                        &request.url,
+                        &request.signed,
+                        request.http_method,
+                        &request.headers,
+                    )
+                    .await
+            }
+        }
+    }
+}
@@ -1,4 +1,19 @@
+#[cfg(feature = "blocking")]
+pub mod blocking;
+pub mod builder;
 pub mod client;
+pub mod clock;
+pub mod credentials;
 pub mod endpoints;
 pub mod error;
+pub mod metrics;
+pub mod middleware;
+pub mod rate_limit;
+pub mod redact;
+pub mod result_code;
+pub mod retry;
+pub mod secret;
 pub mod service;
+#[cfg(feature = "testing")]
+pub mod testing;
+mod trace;
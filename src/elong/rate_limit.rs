@@ -0,0 +1,191 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use tokio::time::Instant;
+
+use super::endpoints::ApiMethod;
+
+/// 限流配置
+///
+/// 每秒允许的请求数（QPS）与突发容量，未单独配置的接口使用默认配置；
+/// 默认配置为空时这些接口不限流。
+#[derive(Debug, Clone, Default)]
+pub struct RateLimitConfig {
+    default: Option<Quota>,
+    methods: HashMap<ApiMethod, Quota>,
+}
+
+/// 令牌桶配额
+///
+/// 直接构造的配额在生效时同样按 [`Quota::per_second`] 与 [`Quota::burst`] 的规则修正。
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quota {
+    /// 每秒补充的令牌数
+    pub per_second: f64,
+    /// 桶容量，即允许的突发请求数
+    pub burst: u32,
+}
+
+/// 最小的每秒令牌数，约 17 分钟一个请求
+pub const MIN_PER_SECOND: f64 = 0.001;
+
+impl Quota {
+    /// 每秒 `per_second` 个请求，突发容量默认取其向上取整
+    ///
+    /// 小于 [`MIN_PER_SECOND`] 的值（包括 0、负数与 `NaN`）按 [`MIN_PER_SECOND`] 处理。
+    pub fn per_second(per_second: f64) -> Self {
+        let per_second = per_second.max(MIN_PER_SECOND);
+        Quota {
+            per_second,
+            burst: per_second.ceil().max(1.0) as u32,
+        }
+    }
+
+    pub fn burst(mut self, burst: u32) -> Self {
+        self.burst = burst.max(1);
+        self
+    }
+
+    /// 修正直接构造时的非法取值
+    fn normalized(self) -> Self {
+        Quota {
+            per_second: self.per_second.max(MIN_PER_SECOND),
+            burst: self.burst.max(1),
+        }
+    }
+}
+
+impl RateLimitConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 所有接口的默认配额
+    pub fn default_quota(mut self, quota: Quota) -> Self {
+        self.default = Some(quota);
+        self
+    }
+
+    /// 指定接口的配额
+    pub fn method(mut self, method: ApiMethod, quota: Quota) -> Self {
+        self.methods.insert(method, quota);
+        self
+    }
+
+    fn quota_for(&self, method: ApiMethod) -> Option<Quota> {
+        self.methods.get(&method).copied().or(self.default)
+    }
+}
+
+struct Bucket {
+    quota: Quota,
+    tokens: f64,
+    updated: Instant,
+}
+
+impl Bucket {
+    fn new(quota: Quota) -> Self {
+        let quota = quota.normalized();
+        Bucket {
+            quota,
+            tokens: quota.burst as f64,
+            updated: Instant::now(),
+        }
+    }
+
+    /// 取出一个令牌，令牌不足时返回需要等待的时间
+    fn take(&mut self) -> Option<Duration> {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.updated).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.quota.per_second).min(self.quota.burst as f64);
+        self.updated = now;
+
+        // 先预扣令牌，等待期间到达的请求会排在后面
+        self.tokens -= 1.0;
+        if self.tokens >= 0.0 {
+            None
+        } else {
+            Some(Duration::from_secs_f64(
+                -self.tokens / self.quota.per_second,
+            ))
+        }
+    }
+
+    /// 归还一个未使用的令牌
+    fn refund(&mut self) {
+        self.tokens = (self.tokens + 1.0).min(self.quota.burst as f64);
+    }
+}
+
+type Buckets = Mutex<HashMap<(String, ApiMethod), Bucket>>;
+
+/// 等待中的预扣令牌，等待被取消时归还
+struct Reservation<'a> {
+    buckets: &'a Buckets,
+    key: Option<(String, ApiMethod)>,
+}
+
+impl Reservation<'_> {
+    fn commit(mut self) {
+        self.key = None;
+    }
+}
+
+impl Drop for Reservation<'_> {
+    fn drop(&mut self) {
+        let Some(key) = self.key.take() else {
+            return;
+        };
+        if let Ok(mut buckets) = self.buckets.lock() {
+            if let Some(bucket) = buckets.get_mut(&key) {
+                bucket.refund();
+            }
+        }
+    }
+}
+
+/// 令牌桶限流器
+///
+/// 内部状态放在 `Arc` 中，`ElongClient` 的所有克隆共享同一组令牌桶；
+/// 令牌桶按账户与接口区分，多个账户共用一个服务时互不影响。
+#[derive(Clone, Default)]
+pub(crate) struct RateLimiter {
+    config: Arc<RateLimitConfig>,
+    buckets: Arc<Buckets>,
+}
+
+impl RateLimiter {
+    pub fn new(config: RateLimitConfig) -> Self {
+        RateLimiter {
+            config: Arc::new(config),
+            buckets: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// 等待直到指定账户的接口有可用配额
+    pub async fn acquire(&self, username: &str, method: ApiMethod) {
+        let Some(quota) = self.config.quota_for(method) else {
+            return;
+        };
+        let key = (username.to_string(), method);
+        let wait = {
+            let mut buckets = self.buckets.lock().unwrap();
+            buckets
+                .entry(key.clone())
+                .or_insert_with(|| Bucket::new(quota))
+                .take()
+        };
+        if let Some(wait) = wait {
+            log::debug!("{} rate limited, waiting {wait:?}", method.name());
+            let reservation = Reservation {
+                buckets: &self.buckets,
+                key: Some(key),
+            };
+            tokio::time::sleep(wait).await;
+            reservation.commit();
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use serde_json::Value;
+
+/// 需要脱敏的字段名，匹配时不区分大小写
+///
+/// 覆盖联系人、入住人、信用卡、发票收件人等结构中的个人信息，与这些结构 `Debug` 输出时脱敏的字段一致。
+/// `Name` 同时用于酒店、城市、房型等名称，只在 [`PERSON_KEYS`] 节点下脱敏。
+const SENSITIVE_KEYS: &[&str] = &[
+    "firstname",
+    "lastname",
+    "holdername",
+    "recipientname",
+    "customername",
+    "mobile",
+    "phone",
+    "fax",
+    "email",
+    "postemail",
+    "number",
+    "cvv",
+    "idno",
+    "idcardno",
+    "expirationyear",
+    "expirationmonth",
+    "expirationdate",
+    "registerphonenum",
+    "registerbanknum",
+    "registeraddress",
+    "customeripaddress",
+    "street",
+];
+
+/// 表示人员的节点名，其下的 `Name` 字段需要脱敏
+const PERSON_KEYS: &[&str] = &["contact", "contacts", "customer", "customers", "recipient"];
+
+/// 字段是否需要脱敏
+pub fn is_sensitive_key(key: &str) -> bool {
+    let key = key.to_lowercase();
+    SENSITIVE_KEYS.contains(&key.as_str())
+}
+
+fn is_person_key(key: &str) -> bool {
+    let key = key.to_lowercase();
+    PERSON_KEYS.contains(&key.as_str())
+}
+
+/// 掩码，仅保留首尾各一个字符
+///
+/// ```
+/// use elong_sdk::elong::redact::mask;
+///
+/// assert_eq!(mask("13800138000"), "1***0");
+/// assert_eq!(mask("张三"), "**");
+/// ```
+pub fn mask(value: &str) -> String {
+    let chars: Vec<char> = value.chars().collect();
+    if chars.len() <= 2 {
+        return "*".repeat(chars.len());
+    }
+    format!("{}***{}", chars[0], chars[chars.len() - 1])
+}
+
+pub(crate) fn mask_opt(value: &Option<String>) -> Option<String> {
+    value.as_deref().map(mask)
+}
+
+/// 对 JSON 中的敏感字段脱敏，无法解析时只输出长度
+pub fn redact_json(json: &str) -> String {
+    match serde_json::from_str::<Value>(json) {
+        Ok(mut value) => {
+            redact_value(&mut value);
+            value.to_string()
+        }
+        Err(_) => format!("<{} bytes non-JSON body>", json.len()),
+    }
+}
+
+/// 对响应字节中的敏感字段脱敏
+pub fn redact_bytes(bytes: &[u8]) -> String {
+    match std::str::from_utf8(bytes) {
+        Ok(json) => redact_json(json),
+        Err(_) => format!("<{} bytes non-UTF-8 body>", bytes.len()),
+    }
+}
+
+/// 对 JSON 值中的敏感字段原地脱敏
+///
+/// 字符串按 [`mask`] 掩码，数字替换为 `0`，保留原有的 JSON 类型以便脱敏后的内容仍可反序列化。
+pub fn redact_value(value: &mut Value) {
+    redact_in(value, false);
+}
+
+fn redact_in(value: &mut Value, person: bool) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if is_sensitive_key(key) || (person && key.eq_ignore_ascii_case("name")) {
+                    match value {
+                        Value::String(s) => *s = mask(s),
+                        Value::Number(n) => *n = 0.into(),
+                        _ => redact_in(value, false),
+                    }
+                } else {
+                    redact_in(value, is_person_key(key));
+                }
+            }
+        }
+        Value::Array(values) => values.iter_mut().for_each(|v| redact_in(v, person)),
+        _ => {}
+    }
+}
@@ -0,0 +1,192 @@
+use std::fmt;
+
+/// 艺龙返回码分类
+///
+/// 艺龙的 `Code` 格式为 `错误码|错误信息`。艺龙文档没有公开完整的错误码表，
+/// 因此按错误信息中的关键字归类，无法归类的保留在 [`ElongResultCode::Unknown`] 中。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ElongResultCode {
+    /// 成功
+    Success,
+    /// 时间戳过期或无效
+    TimestampExpired,
+    /// 签名错误
+    SignatureInvalid,
+    /// 账户不存在、未授权或无权限
+    AuthFailed,
+    /// 系统繁忙或请求过于频繁
+    SystemBusy,
+    /// 系统异常或超时
+    SystemError,
+    /// 库存不足或满房
+    InventoryUnavailable,
+    /// 价格变化
+    PriceChanged,
+    /// 产品不可订
+    ProductUnavailable,
+    /// 订单不存在
+    OrderNotFound,
+    /// 订单不可取消
+    OrderNotCancelable,
+    /// 请求参数错误
+    InvalidParameter,
+    /// 未归类的错误码
+    Unknown(String),
+}
+
+/// 建议的处理方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SuggestedAction {
+    /// 稍后重试
+    Retry,
+    /// 重新询价或重新校验产品后再下单
+    Requote,
+    /// 终止，需要人工或上游处理
+    Abort,
+}
+
+/// 错误信息关键字与分类的对应关系，按顺序匹配，越具体的分类越靠前
+const KEYWORDS: &[(&[&str], ElongResultCode)] = &[
+    (&["时间戳", "timestamp"], ElongResultCode::TimestampExpired),
+    (&["签名", "signature"], ElongResultCode::SignatureInvalid),
+    (
+        &["用户名", "账户", "账号", "权限", "授权", "unauthorized"],
+        ElongResultCode::AuthFailed,
+    ),
+    (
+        &["订单不存在", "order not found"],
+        ElongResultCode::OrderNotFound,
+    ),
+    (
+        &["不可取消", "不能取消", "cannot cancel"],
+        ElongResultCode::OrderNotCancelable,
+    ),
+    (
+        &["系统繁忙", "系统忙", "频繁", "限流", "busy", "qps"],
+        ElongResultCode::SystemBusy,
+    ),
+    (
+        &["系统异常", "系统错误", "超时", "timeout", "system error"],
+        ElongResultCode::SystemError,
+    ),
+    (
+        &["库存", "满房", "房量", "inventory", "sold out"],
+        ElongResultCode::InventoryUnavailable,
+    ),
+    (&["价格", "price"], ElongResultCode::PriceChanged),
+    (
+        &["不可订", "不可预订", "产品", "unavailable"],
+        ElongResultCode::ProductUnavailable,
+    ),
+    (
+        &["参数", "格式", "parameter", "invalid"],
+        ElongResultCode::InvalidParameter,
+    ),
+];
+
+impl ElongResultCode {
+    /// 解析艺龙返回的原始 `Code`
+    pub fn parse(raw: &str) -> Self {
+        let raw = raw.trim();
+        if raw == "0" {
+            return ElongResultCode::Success;
+        }
+        let (code, message) = raw.split_once('|').unwrap_or((raw, ""));
+        Self::from_parts(code.trim(), message.trim())
+    }
+
+    /// 由拆分后的错误码和错误信息归类
+    pub fn from_parts(code: &str, message: &str) -> Self {
+        if code == "0" {
+            return ElongResultCode::Success;
+        }
+        let message = message.to_lowercase();
+        KEYWORDS
+            .iter()
+            .find(|(keywords, _)| keywords.iter().any(|k| message.contains(k)))
+            .map(|(_, result_code)| result_code.clone())
+            .unwrap_or_else(|| ElongResultCode::Unknown(code.to_string()))
+    }
+
+    pub fn is_success(&self) -> bool {
+        *self == ElongResultCode::Success
+    }
+
+    /// 中文描述
+    pub fn description_zh(&self) -> &'static str {
+        match self {
+            ElongResultCode::Success => "成功",
+            ElongResultCode::TimestampExpired => "时间戳过期，请校准服务器时间",
+            ElongResultCode::SignatureInvalid => "签名错误，请检查 appKey 与 appSecret",
+            ElongResultCode::AuthFailed => "账户不存在或无权限访问该接口",
+            ElongResultCode::SystemBusy => "系统繁忙，请稍后再试",
+            ElongResultCode::SystemError => "系统异常，请稍后再试",
+            ElongResultCode::InventoryUnavailable => "库存不足，该房型已满房",
+            ElongResultCode::PriceChanged => "价格已变化，请重新询价",
+            ElongResultCode::ProductUnavailable => "产品不可预订",
+            ElongResultCode::OrderNotFound => "订单不存在",
+            ElongResultCode::OrderNotCancelable => "订单当前状态不可取消",
+            ElongResultCode::InvalidParameter => "请求参数错误",
+            ElongResultCode::Unknown(_) => "未知错误",
+        }
+    }
+
+    /// 英文描述
+    pub fn description_en(&self) -> &'static str {
+        match self {
+            ElongResultCode::Success => "Success",
+            ElongResultCode::TimestampExpired => {
+                "Timestamp expired, please synchronize the server clock"
+            }
+            ElongResultCode::SignatureInvalid => {
+                "Invalid signature, please check the app key and app secret"
+            }
+            ElongResultCode::AuthFailed => "Account does not exist or has no access to this API",
+            ElongResultCode::SystemBusy => "System busy, please try again later",
+            ElongResultCode::SystemError => "System error, please try again later",
+            ElongResultCode::InventoryUnavailable => "No inventory left for this room",
+            ElongResultCode::PriceChanged => "Price has changed, please quote again",
+            ElongResultCode::ProductUnavailable => "Product is not available for booking",
+            ElongResultCode::OrderNotFound => "Order not found",
+            ElongResultCode::OrderNotCancelable => {
+                "Order cannot be cancelled in its current status"
+            }
+            ElongResultCode::InvalidParameter => "Invalid request parameter",
+            ElongResultCode::Unknown(_) => "Unknown error",
+        }
+    }
+
+    /// 按语言返回描述，`local` 以 `en` 开头时返回英文
+    pub fn description(&self, local: &str) -> &'static str {
+        if local.starts_with("en") {
+            self.description_en()
+        } else {
+            self.description_zh()
+        }
+    }
+
+    /// 建议的处理方式，成功时为 `None`
+    pub fn suggested_action(&self) -> Option<SuggestedAction> {
+        match self {
+            ElongResultCode::Success => None,
+            ElongResultCode::TimestampExpired
+            | ElongResultCode::SystemBusy
+            | ElongResultCode::SystemError => Some(SuggestedAction::Retry),
+            ElongResultCode::InventoryUnavailable
+            | ElongResultCode::PriceChanged
+            | ElongResultCode::ProductUnavailable => Some(SuggestedAction::Requote),
+            ElongResultCode::SignatureInvalid
+            | ElongResultCode::AuthFailed
+            | ElongResultCode::OrderNotFound
+            | ElongResultCode::OrderNotCancelable
+            | ElongResultCode::InvalidParameter
+            | ElongResultCode::Unknown(_) => Some(SuggestedAction::Abort),
+        }
+    }
+}
+
+impl fmt::Display for ElongResultCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.description_zh())
+    }
+}
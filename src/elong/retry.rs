@@ -0,0 +1,100 @@
+use std::{
+    collections::{hash_map::RandomState, HashMap},
+    hash::{BuildHasher, Hasher},
+    time::Duration,
+};
+
+use super::endpoints::ApiMethod;
+
+/// 重试策略
+///
+/// 采用指数退避，第 n 次重试前等待 `initial_backoff * multiplier^(n-1)`，
+/// 不超过 `max_backoff`；开启 `jitter` 时在 `[0, 退避时间]` 内随机取值。
+/// 默认只有幂等的查询接口会自动重试，见 [`ApiMethod::is_idempotent`]。
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// 最大尝试次数（含首次请求）
+    pub max_attempts: u32,
+    /// 首次重试前的等待时间
+    pub initial_backoff: Duration,
+    /// 最长等待时间
+    pub max_backoff: Duration,
+    /// 退避倍数
+    pub multiplier: u32,
+    /// 是否加入随机抖动
+    pub jitter: bool,
+    /// 按接口覆盖是否允许重试
+    overrides: HashMap<ApiMethod, bool>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(200),
+            max_backoff: Duration::from_secs(5),
+            multiplier: 2,
+            jitter: true,
+            overrides: HashMap::new(),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// 不重试
+    pub fn none() -> Self {
+        RetryPolicy {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    pub fn backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.initial_backoff = initial;
+        self.max_backoff = max;
+        self
+    }
+
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// 为指定接口开启或关闭重试，覆盖默认的幂等判断
+    pub fn retry_method(mut self, method: ApiMethod, enabled: bool) -> Self {
+        self.overrides.insert(method, enabled);
+        self
+    }
+
+    /// 指定接口是否允许重试
+    pub fn is_enabled_for(&self, method: ApiMethod) -> bool {
+        self.overrides
+            .get(&method)
+            .copied()
+            .unwrap_or_else(|| method.is_idempotent())
+    }
+
+    /// 第 `attempt` 次失败后（从 1 开始）的等待时间
+    pub fn backoff_for(&self, attempt: u32) -> Duration {
+        let factor = self.multiplier.saturating_pow(attempt.saturating_sub(1));
+        let backoff = self
+            .initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff);
+        if self.jitter {
+            let millis = backoff.as_millis() as u64;
+            Duration::from_millis(random_u64() % (millis + 1))
+        } else {
+            backoff
+        }
+    }
+}
+
+fn random_u64() -> u64 {
+    RandomState::new().build_hasher().finish()
+}
@@ -1,47 +1,49 @@
-use std::env;
+use std::{env, sync::Arc};
 
 use async_trait::async_trait;
 
 use crate::{
+    network::transport::Transport,
     request::{
-        data_booking::DataBookingRequest, data_inventory::InventoryRequest,
-        data_rate::DataRateRequest, data_rp::DataRpRequest, data_validate::DataValidateRequest,
-        dictionary::DictionaryRequest, exchangerate::ExchangerateRequest,
-        hotel_detail_request::HotelDetailRequest, hotel_rate_min_request::HotelRateMinRequest,
-        incr_id::IncrIdRequest, incr_inv::IncrInvRequest, incr_order::IncrOrderRequest,
-        incr_rate::IncrRateRequest, incr_state::IncrStateRequest,
-        order_addinvoice::OrderAddinvoiceRequest, order_cancel::OrderCancelRequest,
-        order_create::OrderCreateRequest, order_detail::OrderDetailRequest,
-        order_feedback::OrderFeedbackRequest, order_list::OrderListRequest,
-        order_pay::OrderPayRequest, order_pay_confirm::OrderPayConfirmRequest,
-        order_promote::OrderPromoteRequest, order_related::OrderRelatedRequest,
-        static_brand::StaticBrandRequest, static_city::*, static_grade::StaticGradeRequest,
-        static_group::StaticGroupRequest, static_info::StaticInfoRequest,
+        api_request::ApiCall,
+        data_booking::DataBookingRequest,
+        data_inventory::InventoryRequest,
+        data_rate::DataRateRequest,
+        data_rp::DataRpRequest,
+        data_validate::DataValidateRequest,
+        dictionary::DictionaryRequest,
+        exchangerate::ExchangerateRequest,
+        hotel_detail_request::{HotelCrawlDetailRequest, HotelDetailRequest},
+        hotel_rate_min_request::HotelRateMinRequest,
+        incr_id::{IncrIdRequest, IncrShardingIdRequest},
+        incr_inv::{IncrInvRequest, IncrShardingInvRequest},
+        incr_order::IncrOrderRequest,
+        incr_rate::{IncrRateRequest, IncrShardingRateRequest},
+        incr_state::{IncrShardingStateRequest, IncrStateRequest},
+        order_addinvoice::OrderAddinvoiceRequest,
+        order_cancel::OrderCancelRequest,
+        order_create::OrderCreateRequest,
+        order_detail::OrderDetailRequest,
+        order_feedback::OrderFeedbackRequest,
+        order_list::OrderListRequest,
+        order_pay::OrderPayRequest,
+        order_pay_confirm::OrderPayConfirmRequest,
+        order_promote::OrderPromoteRequest,
+        order_related::OrderRelatedRequest,
+        static_brand::StaticBrandRequest,
+        static_city::*,
+        static_grade::StaticGradeRequest,
+        static_group::StaticGroupRequest,
+        static_info::StaticInfoRequest,
         static_list::StaticListRequest,
     },
-    response::{
-        api_response::ElongResponse, data_booking::DataBookingResponse,
-        data_inventory::InventoryResponse, data_rate::DataRateResponse, data_rp::DataRpResponse,
-        data_validate::DataValidateResponse, dictionary::DictionaryResponse,
-        exchangerate::ExchangerateResponse, hotel_detail_response::HotelDetailResponse,
-        hotel_rate_min_response::HotelRateMinResponse, incr_id::IncrIdResponse,
-        incr_inv::IncrInvResponse, incr_order::IncrOrderResponse, incr_rate::IncrRateResponse,
-        incr_state::IncrStateResponse, order_addinvoice::OrderAddinvoiceResponse,
-        order_cancel::OrderCancelResponse, order_create::OrderCreateResponse,
-        order_detail::OrderDetailReponse, order_feedback::OrderFeedbackResponse,
-        order_list::OrderListResponse, order_pay::OrderPayResponse,
-        order_pay_confirm::OrderPayConfirmResponse, order_promote::OrderPromoteResponse,
-        order_related::OrderRelatedResponse, static_brand::StaticBrandResponse, static_city::*,
-        static_grade::StaticGradeResponse, static_group::StaticGroupResponse,
-        static_info::StaticInfoResponse, static_list::StaticListResponse,
-    },
+    response::api_response::{BaseResponse, ElongResponse},
     types::*,
     Elong,
 };
 
 use super::{
-    client::ElongClient,
-    endpoints::{ApiEndpoint, ApiMethod},
+    builder::ElongServiceBuilder, client::ElongClient, endpoints::ApiEndpoint, error::ElongError,
 };
 
 #[derive(Clone)]
@@ -50,13 +52,87 @@ pub struct ElongService {
     pub url: String,
 }
 
+/// 同 [`ElongService::new`]，失败时 panic
 impl Default for ElongService {
+    #[allow(deprecated)]
     fn default() -> Self {
         Self::new()
     }
 }
 
 impl ElongService {
+    /// 调用接口，接口名与响应类型由请求类型决定
+    ///
+    /// 可用于调用 `Elong` trait 之外、自行实现了 [`ApiCall`] 的接口。
+    pub async fn call<R>(&self, request: R) -> Result<ElongResponse<R::Response>, ElongError>
+    where
+        R: ApiCall,
+        ElongResponse<R::Response>: BaseResponse,
+    {
+        self.client.call(&self.url, request).await
+    }
+
+    /// 调用任意接口，请求与响应均为 JSON，见 [`ElongClient::call_raw`]
+    pub async fn call_raw(
+        &self,
+        method_name: &str,
+        request: serde_json::Value,
+    ) -> Result<ElongResponse<serde_json::Value>, ElongError> {
+        self.client.call_raw(&self.url, method_name, request).await
+    }
+
+    /// 使用指定语言调用的服务视图
+    ///
+    /// 返回的服务与原服务共享连接池、限流与时钟校准，只影响通过它发起的调用：
+    ///
+    /// ```ignore
+    /// let detail = service.with_local("en-US").hotel_detail(request).await?;
+    /// ```
+    pub fn with_local(&self, local: impl Into<String>) -> Self {
+        ElongService {
+            client: self.client.clone().with_local(local),
+            url: self.url.clone(),
+        }
+    }
+
+    /// 使用指定账户调用的服务视图，账户名由 [`CredentialProvider`] 解析
+    ///
+    /// 多个渠道或租户共用一个服务时，各自的调用共享连接池：
+    ///
+    /// ```ignore
+    /// let detail = service.with_account("channel_a").hotel_detail(request).await?;
+    /// ```
+    ///
+    /// [`CredentialProvider`]: super::credentials::CredentialProvider
+    pub fn with_account(&self, account: impl Into<String>) -> Self {
+        ElongService {
+            client: self.client.clone().with_account(Some(account.into())),
+            url: self.url.clone(),
+        }
+    }
+
+    /// 使用指定版本调用的服务视图，覆盖按接口固定的版本
+    pub fn with_version(&self, version: impl Into<String>) -> Self {
+        ElongService {
+            client: self.client.clone().override_version(version.into()),
+            url: self.url.clone(),
+        }
+    }
+
+    /// 构建器，可设置账户、接口地址、超时、代理、连接池等
+    pub fn builder() -> ElongServiceBuilder {
+        ElongServiceBuilder::new()
+    }
+
+    /// 从环境变量 `ELONG_USERNAME`、`ELONG_APP_KEY`、`ELONG_APP_SECRET` 读取账户信息
+    ///
+    /// # Panics
+    ///
+    /// 环境变量未设置或无法创建 HTTP 客户端时 panic。
+    #[deprecated(
+        note = "use `ElongService::builder().build()`, which returns an error instead of panicking"
+    )]
+    #[allow(deprecated)]
     pub fn new() -> Self {
         let username =
             env::var("ELONG_USERNAME").expect("Environment variable ELONG_USERNAME is not set");
@@ -69,6 +145,13 @@ impl ElongService {
         ElongService { client, url }
     }
 
+    /// # Panics
+    ///
+    /// 无法创建 HTTP 客户端时 panic。
+    #[deprecated(
+        note = "use `ElongService::builder()`, which returns an error instead of panicking"
+    )]
+    #[allow(deprecated)]
     pub fn new_with_endpoint(
         username: String,
         app_key: String,
@@ -79,322 +162,195 @@ impl ElongService {
         let url = api_endpoints.url();
         ElongService { client, url }
     }
+
+    /// 使用自定义传输层创建服务
+    pub fn new_with_transport(
+        username: String,
+        app_key: String,
+        app_secret: String,
+        api_endpoints: ApiEndpoint,
+        transport: Arc<dyn Transport>,
+    ) -> Self {
+        let client = ElongClient::with_transport(username, app_key, app_secret, transport);
+        let url = api_endpoints.url();
+        ElongService { client, url }
+    }
 }
 
 #[async_trait]
 impl Elong for ElongService {
     /// 城市列表
     async fn get_static_city(&self, request: StaticCityRequest) -> RECityResp {
-        let res: ElongResponse<StaticCityResponse> = self
-            .client
-            .fetch_data(&self.url, ApiMethod::StaticCity, request)
-            .await?;
-        Ok(res)
+        self.call(request).await
     }
 
     /// 酒店列表
     async fn get_static_list(&self, request: StaticListRequest) -> REListResp {
-        let res: ElongResponse<StaticListResponse> = self
-            .client
-            .fetch_data(&self.url, ApiMethod::StaticList, request)
-            .await?;
-        Ok(res)
+        self.call(request).await
     }
 
     /// 酒店详情
     async fn get_static_info(&self, request: StaticInfoRequest) -> REInfoResp {
-        let res: ElongResponse<StaticInfoResponse> = self
-            .client
-            .fetch_data(&self.url, ApiMethod::StaticInfo, request)
-            .await?;
-        Ok(res)
+        self.call(request).await
     }
 
     /// 点评评分
     async fn get_static_grade(&self, request: StaticGradeRequest) -> REGradeResp {
-        let res: ElongResponse<StaticGradeResponse> = self
-            .client
-            .fetch_data(&self.url, ApiMethod::StaticGrade, request)
-            .await?;
-        Ok(res)
+        self.call(request).await
     }
 
     /// 酒店品牌
     async fn get_static_brand(&self, request: StaticBrandRequest) -> REBrandResp {
-        let res: ElongResponse<StaticBrandResponse> = self
-            .client
-            .fetch_data(&self.url, ApiMethod::StaticBrand, request)
-            .await?;
-        Ok(res)
+        self.call(request).await
     }
 
     /// 酒店集团
     async fn get_static_group(&self, request: StaticGroupRequest) -> REGroupResp {
-        let res: ElongResponse<StaticGroupResponse> = self
-            .client
-            .fetch_data(&self.url, ApiMethod::StaticGroup, request)
-            .await?;
-        Ok(res)
+        self.call(request).await
     }
 
     /// 酒店字典
     async fn get_hotel_dictionary(&self, request: DictionaryRequest) -> REDictionaryResp {
-        let res: ElongResponse<DictionaryResponse> = self
-            .client
-            .fetch_data(&self.url, ApiMethod::Dictionary, request)
-            .await?;
-        Ok(res)
+        self.call(request).await
     }
 
     /// 产品详情
     async fn get_data_rp(&self, request: DataRpRequest) -> REDataRpResp {
-        let res: ElongResponse<DataRpResponse> = self
-            .client
-            .fetch_data(&self.url, ApiMethod::DataRp, request)
-            .await?;
-        Ok(res)
+        self.call(request).await
     }
 
     /// 增量编号
     async fn get_incr_id(&self, request: IncrIdRequest) -> REIncrIdResp {
-        let res: ElongResponse<IncrIdResponse> = self
-            .client
-            .fetch_data(&self.url, ApiMethod::IncrId, request)
-            .await?;
-        Ok(res)
+        self.call(request).await
     }
 
     /// 增量编号分片
     async fn get_incr_sharding_id(&self, request: IncrIdRequest) -> REIncrIdResp {
-        let res: ElongResponse<IncrIdResponse> = self
-            .client
-            .fetch_data(&self.url, ApiMethod::IncrShardingId, request)
-            .await?;
-        Ok(res)
+        self.call(IncrShardingIdRequest(request)).await
     }
 
     /// 状态增量
     async fn get_incr_state(&self, request: IncrStateRequest) -> REIncrStateResp {
-        let res: ElongResponse<IncrStateResponse> = self
-            .client
-            .fetch_data(&self.url, ApiMethod::IncrState, request)
-            .await?;
-        Ok(res)
+        self.call(request).await
     }
 
     /// 状态增量分片
     async fn get_incr_sharding_state(&self, request: IncrStateRequest) -> REIncrStateResp {
-        let res: ElongResponse<IncrStateResponse> = self
-            .client
-            .fetch_data(&self.url, ApiMethod::IncrShardingState, request)
-            .await?;
-        Ok(res)
+        self.call(IncrShardingStateRequest(request)).await
     }
 
     /// 库存全量
     async fn get_inventory(&self, request: InventoryRequest) -> REInvResp {
-        let res: ElongResponse<InventoryResponse> = self
-            .client
-            .fetch_data(&self.url, ApiMethod::DataInventory, request)
-            .await?;
-        Ok(res)
+        self.call(request).await
     }
 
     /// 库存增量
     async fn get_incr_inv(&self, request: IncrInvRequest) -> REIncrInvResp {
-        let res: ElongResponse<IncrInvResponse> = self
-            .client
-            .fetch_data(&self.url, ApiMethod::IncrInv, request)
-            .await?;
-        Ok(res)
+        self.call(request).await
     }
 
     /// 库存增量切片
     async fn get_incr_sharding_inv(&self, request: IncrInvRequest) -> REIncrInvResp {
-        let res: ElongResponse<IncrInvResponse> = self
-            .client
-            .fetch_data(&self.url, ApiMethod::IncrShardingInv, request)
-            .await?;
-        Ok(res)
+        self.call(IncrShardingInvRequest(request)).await
     }
 
     /// 价格全量
     async fn get_data_rate(&self, request: DataRateRequest) -> RERateResp {
-        let res: ElongResponse<DataRateResponse> = self
-            .client
-            .fetch_data(&self.url, ApiMethod::DataRate, request)
-            .await?;
-        Ok(res)
+        self.call(request).await
     }
 
     /// 价格增量
     async fn get_incr_rate(&self, request: IncrRateRequest) -> REIncrRateResp {
-        let res: ElongResponse<IncrRateResponse> = self
-            .client
-            .fetch_data(&self.url, ApiMethod::IncrRate, request)
-            .await?;
-        Ok(res)
+        self.call(request).await
     }
 
     /// 价格增量分片
     async fn get_incr_sharding_rate(&self, request: IncrRateRequest) -> REIncrRateResp {
-        let res: ElongResponse<IncrRateResponse> = self
-            .client
-            .fetch_data(&self.url, ApiMethod::ShardingRate, request)
-            .await?;
-        Ok(res)
+        self.call(IncrShardingRateRequest(request)).await
     }
 
     /// 数据校验
     async fn data_validate(&self, request: DataValidateRequest) -> REDataValidateResp {
-        let res: ElongResponse<DataValidateResponse> = self
-            .client
-            .fetch_data(&self.url, ApiMethod::DataValidate, request)
-            .await?;
-        Ok(res)
+        self.call(request).await
     }
 
     /// 预订数据
     async fn data_booking(&self, request: DataBookingRequest) -> REDataBookingResp {
-        let res: ElongResponse<DataBookingResponse> = self
-            .client
-            .fetch_data(&self.url, ApiMethod::DataBooking, request)
-            .await?;
-        Ok(res)
+        self.call(request).await
     }
 
     /// 创建订单
     async fn order_create(&self, request: OrderCreateRequest) -> REOrderCreateResp {
-        let res: ElongResponse<OrderCreateResponse> = self
-            .client
-            .fetch_data(&self.url, ApiMethod::OrderCreate, request)
-            .await?;
-        Ok(res)
+        self.call(request).await
     }
 
     /// 订单支付
     async fn order_pay(&self, request: OrderPayRequest) -> REOrderPayResp {
-        let res: ElongResponse<OrderPayResponse> = self
-            .client
-            .fetch_data(&self.url, ApiMethod::OrderPay, request)
-            .await?;
-        Ok(res)
+        self.call(request).await
     }
 
     /// 订单支付确认
     async fn order_pay_confirm(&self, request: OrderPayConfirmRequest) -> REOrderPayConfirmResp {
-        let res: ElongResponse<OrderPayConfirmResponse> = self
-            .client
-            .fetch_data(&self.url, ApiMethod::OrderPayConfirm, request)
-            .await?;
-        Ok(res)
+        self.call(request).await
     }
 
     /// 订单增量
     async fn order_incr(&self, request: IncrOrderRequest) -> REIncrOrderResp {
-        let res: ElongResponse<IncrOrderResponse> = self
-            .client
-            .fetch_data(&self.url, ApiMethod::IncrOrder, request)
-            .await?;
-        Ok(res)
+        self.call(request).await
     }
 
     /// 订单详情
     async fn order_detail(&self, request: OrderDetailRequest) -> REOrderDetailResp {
-        let res: ElongResponse<OrderDetailReponse> = self
-            .client
-            .fetch_data(&self.url, ApiMethod::OrderDetail, request)
-            .await?;
-        Ok(res)
+        self.call(request).await
     }
 
     /// 取消订单
     async fn order_cancel(&self, request: OrderCancelRequest) -> REOrderCancelResp {
-        let res: ElongResponse<OrderCancelResponse> = self
-            .client
-            .fetch_data(&self.url, ApiMethod::OrderCancel, request)
-            .await?;
-        Ok(res)
+        self.call(request).await
     }
 
     /// 订单崔确认
     async fn order_promote(&self, request: OrderPromoteRequest) -> REOrderPromoteResp {
-        let res: ElongResponse<OrderPromoteResponse> = self
-            .client
-            .fetch_data(&self.url, ApiMethod::OrderPromote, request)
-            .await?;
-        Ok(res)
+        self.call(request).await
     }
 
     /// 关联订单
     async fn order_related(&self, request: OrderRelatedRequest) -> REOrderRelatedResp {
-        let res: ElongResponse<OrderRelatedResponse> = self
-            .client
-            .fetch_data(&self.url, ApiMethod::OrderRelated, request)
-            .await?;
-        Ok(res)
+        self.call(request).await
     }
 
     /// 入住反馈
     async fn order_feedback(&self, request: OrderFeedbackRequest) -> REOrderFeedbackResp {
-        let res: ElongResponse<OrderFeedbackResponse> = self
-            .client
-            .fetch_data(&self.url, ApiMethod::OrderFeedback, request)
-            .await?;
-        Ok(res)
+        self.call(request).await
     }
 
     /// 补开发票
     async fn order_addinvoice(&self, request: OrderAddinvoiceRequest) -> REOrderAddinvoiceResp {
-        let res: ElongResponse<OrderAddinvoiceResponse> = self
-            .client
-            .fetch_data(&self.url, ApiMethod::OrderAddinvoice, request)
-            .await?;
-        Ok(res)
+        self.call(request).await
     }
 
     /// 订单列表
     async fn order_list(&self, request: OrderListRequest) -> REOrderListResp {
-        let res: ElongResponse<OrderListResponse> = self
-            .client
-            .fetch_data(&self.url, ApiMethod::OrderList, request)
-            .await?;
-        Ok(res)
+        self.call(request).await
     }
 
     /// 汇率
     async fn exchangerate(&self, request: ExchangerateRequest) -> REExchangeRateResp {
-        let res: ElongResponse<ExchangerateResponse> = self
-            .client
-            .fetch_data(&self.url, ApiMethod::ExchangeRate, request)
-            .await?;
-        Ok(res)
+        self.call(request).await
     }
 
     /// 酒店详情搜索
     async fn hotel_detail(&self, request: HotelDetailRequest) -> REHotelDetailResp {
-        let res: ElongResponse<HotelDetailResponse> = self
-            .client
-            .fetch_data(&self.url, ApiMethod::HotelDetail, request)
-            .await?;
-        Ok(res)
+        self.call(request).await
     }
 
     /// 酒店爬虫详情
     async fn hotel_crawl_detail(&self, request: HotelDetailRequest) -> REHotelDetailResp {
-        let res: ElongResponse<HotelDetailResponse> = self
-            .client
-            .fetch_data(&self.url, ApiMethod::HotelCrawlDetail, request)
-            .await?;
-        Ok(res)
+        self.call(HotelCrawlDetailRequest(request)).await
     }
 
     /// 酒店最低价
     async fn hotel_rate_min(&self, request: HotelRateMinRequest) -> REHotelRateMinResp {
-        let res: ElongResponse<HotelRateMinResponse> = self
-            .client
-            .fetch_data(&self.url, ApiMethod::HotelRateMin, request)
-            .await?;
-        Ok(res)
+        self.call(request).await
     }
 }
@@ -0,0 +1,259 @@
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{
+    request::{
+        api_request::ApiCall, data_booking::DataBookingRequest, data_inventory::InventoryRequest,
+        data_rate::DataRateRequest, data_rp::DataRpRequest, data_validate::DataValidateRequest,
+        dictionary::DictionaryRequest, exchangerate::ExchangerateRequest,
+        hotel_detail_request::HotelDetailRequest, hotel_rate_min_request::HotelRateMinRequest,
+        incr_id::IncrIdRequest, incr_inv::IncrInvRequest, incr_order::IncrOrderRequest,
+        incr_rate::IncrRateRequest, incr_state::IncrStateRequest,
+        order_addinvoice::OrderAddinvoiceRequest, order_cancel::OrderCancelRequest,
+        order_create::OrderCreateRequest, order_detail::OrderDetailRequest,
+        order_feedback::OrderFeedbackRequest, order_list::OrderListRequest,
+        order_pay::OrderPayRequest, order_pay_confirm::OrderPayConfirmRequest,
+        order_promote::OrderPromoteRequest, order_related::OrderRelatedRequest,
+        static_brand::StaticBrandRequest, static_city::StaticCityRequest,
+        static_grade::StaticGradeRequest, static_group::StaticGroupRequest,
+        static_info::StaticInfoRequest, static_list::StaticListRequest,
+    },
+    response::api_response::ElongResponse,
+    types::*,
+    Elong,
+};
+
+use super::error::{ApiError, ElongError};
+
+type Handler<Req, Resp> = Arc<dyn Fn(Req) -> Resp + Send + Sync>;
+
+/// `Elong` 的测试替身
+///
+/// 通过 `on_<方法名>` 为每个方法设置返回值或错误，收到的请求会被记录下来用于断言；
+/// 未设置的方法返回 [`ElongError::ConfigError`]。需要开启 `testing` 特性。
+///
+/// ```
+/// use elong_sdk::elong::testing::MockElong;
+/// use elong_sdk::response::incr_id::IncrIdResponse;
+///
+/// let mock = MockElong::new();
+/// mock.on_get_incr_id(|_| MockElong::ok(IncrIdResponse { last_id: 1 }));
+/// ```
+#[derive(Default)]
+pub struct MockElong {
+    handlers: Mutex<Handlers>,
+    calls: Mutex<Vec<MockCall>>,
+}
+
+/// 一次调用记录
+#[derive(Debug, Clone, PartialEq)]
+pub struct MockCall {
+    /// `Elong` 的方法名，如 `order_create`
+    pub method: &'static str,
+    /// 请求序列化后的 JSON
+    pub request: Value,
+}
+
+impl MockElong {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 成功响应
+    pub fn ok<T>(result: T) -> Result<ElongResponse<T>, ElongError> {
+        Ok(ElongResponse {
+            code: "0".to_string(),
+            result: Some(result),
+            guid: None,
+        })
+    }
+
+    /// 业务错误，`raw_code` 格式同艺龙的 `Code`，如 `H001022|房量不足`
+    ///
+    /// 错误的 `method` 在返回时填为所调用接口的名称，如 `hotel.order.create`。
+    pub fn api_error<T>(raw_code: &str) -> Result<ElongResponse<T>, ElongError> {
+        Err(ElongError::ApiError(ApiError::new(raw_code, None, "")))
+    }
+
+    /// 所有调用记录
+    pub fn calls(&self) -> Vec<MockCall> {
+        self.calls.lock().unwrap().clone()
+    }
+
+    /// 指定方法收到的请求
+    pub fn calls_to(&self, method: &str) -> Vec<Value> {
+        self.calls
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|call| call.method == method)
+            .map(|call| call.request.clone())
+            .collect()
+    }
+
+    /// 指定方法被调用的次数
+    pub fn call_count(&self, method: &str) -> usize {
+        self.calls
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|call| call.method == method)
+            .count()
+    }
+
+    /// 清空调用记录，已设置的返回值保持不变
+    pub fn reset_calls(&self) {
+        self.calls.lock().unwrap().clear();
+    }
+
+    fn record(&self, method: &'static str, request: &impl Serialize) {
+        let request = serde_json::to_value(request).unwrap_or(Value::Null);
+        self.calls
+            .lock()
+            .unwrap()
+            .push(MockCall { method, request });
+    }
+}
+
+/// 为 [`MockElong::api_error`] 构造的错误补上接口名
+fn with_method<T>(
+    result: Result<ElongResponse<T>, ElongError>,
+    method: &str,
+) -> Result<ElongResponse<T>, ElongError> {
+    result.map_err(|err| match err {
+        ElongError::ApiError(mut err) if err.method.is_empty() => {
+            err.method = method.to_string();
+            ElongError::ApiError(err)
+        }
+        err => err,
+    })
+}
+
+fn unconfigured(method: &str) -> ElongError {
+    ElongError::ConfigError(format!(
+        "MockElong: no response configured for {method}, call on_{method} first"
+    ))
+}
+
+macro_rules! mock_methods {
+    ($($(#[$doc:meta])* fn $name:ident / $on:ident($req:ty) -> $resp:ty;)*) => {
+        #[derive(Default)]
+        struct Handlers {
+            $($name: Option<Handler<$req, $resp>>,)*
+        }
+
+        impl MockElong {
+            $(
+                $(#[$doc])*
+                pub fn $on(&self, handler: impl Fn($req) -> $resp + Send + Sync + 'static) -> &Self {
+                    self.handlers.lock().unwrap().$name = Some(Arc::new(handler));
+                    self
+                }
+            )*
+
+            /// 已设置返回值的方法
+            pub fn configured(&self) -> Vec<&'static str> {
+                let handlers = self.handlers.lock().unwrap();
+                let mut configured = Vec::new();
+                $(
+                    if handlers.$name.is_some() {
+                        configured.push(stringify!($name));
+                    }
+                )*
+                configured
+            }
+        }
+
+        #[async_trait]
+        impl Elong for MockElong {
+            $(
+                async fn $name(&self, req: $req) -> $resp {
+                    self.record(stringify!($name), &req);
+                    let handler = self.handlers.lock().unwrap().$name.clone();
+                    match handler {
+                        Some(handler) => {
+                            with_method(handler(req), <$req as ApiCall>::METHOD.name())
+                        }
+                        None => Err(unconfigured(stringify!($name))),
+                    }
+                }
+            )*
+        }
+    };
+}
+
+mock_methods! {
+    /// 城市列表
+    fn get_static_city / on_get_static_city(StaticCityRequest) -> RECityResp;
+    /// 酒店列表
+    fn get_static_list / on_get_static_list(StaticListRequest) -> REListResp;
+    /// 酒店详情
+    fn get_static_info / on_get_static_info(StaticInfoRequest) -> REInfoResp;
+    /// 点评评分
+    fn get_static_grade / on_get_static_grade(StaticGradeRequest) -> REGradeResp;
+    /// 酒店品牌
+    fn get_static_brand / on_get_static_brand(StaticBrandRequest) -> REBrandResp;
+    /// 酒店集团
+    fn get_static_group / on_get_static_group(StaticGroupRequest) -> REGroupResp;
+    /// 酒店字典
+    fn get_hotel_dictionary / on_get_hotel_dictionary(DictionaryRequest) -> REDictionaryResp;
+    /// 产品详情
+    fn get_data_rp / on_get_data_rp(DataRpRequest) -> REDataRpResp;
+    /// 状态增量
+    fn get_incr_state / on_get_incr_state(IncrStateRequest) -> REIncrStateResp;
+    /// 库存全量
+    fn get_inventory / on_get_inventory(InventoryRequest) -> REInvResp;
+    /// 库存增量
+    fn get_incr_inv / on_get_incr_inv(IncrInvRequest) -> REIncrInvResp;
+    /// 库存增量分片
+    fn get_incr_sharding_inv / on_get_incr_sharding_inv(IncrInvRequest) -> REIncrInvResp;
+    /// 价格全量
+    fn get_data_rate / on_get_data_rate(DataRateRequest) -> RERateResp;
+    /// 价格增量
+    fn get_incr_rate / on_get_incr_rate(IncrRateRequest) -> REIncrRateResp;
+    /// 价格增量分片
+    fn get_incr_sharding_rate / on_get_incr_sharding_rate(IncrRateRequest) -> REIncrRateResp;
+    /// 增量编号
+    fn get_incr_id / on_get_incr_id(IncrIdRequest) -> REIncrIdResp;
+    /// 增量编号分片
+    fn get_incr_sharding_id / on_get_incr_sharding_id(IncrIdRequest) -> REIncrIdResp;
+    /// 状态增量分片
+    fn get_incr_sharding_state / on_get_incr_sharding_state(IncrStateRequest) -> REIncrStateResp;
+    /// 数据验证
+    fn data_validate / on_data_validate(DataValidateRequest) -> REDataValidateResp;
+    /// 预订数据
+    fn data_booking / on_data_booking(DataBookingRequest) -> REDataBookingResp;
+    /// 创建订单
+    fn order_create / on_order_create(OrderCreateRequest) -> REOrderCreateResp;
+    /// 订单支付
+    fn order_pay / on_order_pay(OrderPayRequest) -> REOrderPayResp;
+    /// 订单支付确认
+    fn order_pay_confirm / on_order_pay_confirm(OrderPayConfirmRequest) -> REOrderPayConfirmResp;
+    /// 订单增量
+    fn order_incr / on_order_incr(IncrOrderRequest) -> REIncrOrderResp;
+    /// 订单详情
+    fn order_detail / on_order_detail(OrderDetailRequest) -> REOrderDetailResp;
+    /// 取消订单
+    fn order_cancel / on_order_cancel(OrderCancelRequest) -> REOrderCancelResp;
+    /// 订单催确认
+    fn order_promote / on_order_promote(OrderPromoteRequest) -> REOrderPromoteResp;
+    /// 关联订单
+    fn order_related / on_order_related(OrderRelatedRequest) -> REOrderRelatedResp;
+    /// 入住反馈
+    fn order_feedback / on_order_feedback(OrderFeedbackRequest) -> REOrderFeedbackResp;
+    /// 补开发票
+    fn order_addinvoice / on_order_addinvoice(OrderAddinvoiceRequest) -> REOrderAddinvoiceResp;
+    /// 订单列表
+    fn order_list / on_order_list(OrderListRequest) -> REOrderListResp;
+    /// 汇率
+    fn exchangerate / on_exchangerate(ExchangerateRequest) -> REExchangeRateResp;
+    /// 酒店详情搜索
+    fn hotel_detail / on_hotel_detail(HotelDetailRequest) -> REHotelDetailResp;
+    /// 酒店详情搜索
+    fn hotel_crawl_detail / on_hotel_crawl_detail(HotelDetailRequest) -> REHotelDetailResp;
+    /// 酒店最小价
+    fn hotel_rate_min / on_hotel_rate_min(HotelRateMinRequest) -> REHotelRateMinResp;
+}
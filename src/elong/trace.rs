@@ -0,0 +1,93 @@
+use std::{future::Future, time::Duration};
+
+use crate::response::api_response::ElongResponse;
+
+use super::{endpoints::ApiMethod, error::ElongError};
+
+/// 一次接口调用的 span
+///
+/// 开启 `tracing` 特性时每次调用生成名为 `elong.call` 的 span，记录接口名、请求大小、
+/// HTTP 状态码、艺龙 `Code`、`Guid`、重试次数与耗时；未开启时不做任何事。
+pub(crate) struct CallSpan {
+    #[cfg(feature = "tracing")]
+    span: tracing::Span,
+}
+
+#[cfg(feature = "tracing")]
+impl CallSpan {
+    pub fn new(method: ApiMethod) -> Self {
+        let span = tracing::info_span!(
+            "elong.call",
+            method = method.name(),
+            request_size = tracing::field::Empty,
+            http_status = tracing::field::Empty,
+            code = tracing::field::Empty,
+            guid = tracing::field::Empty,
+            retries = 0u32,
+            elapsed_ms = tracing::field::Empty,
+            error = tracing::field::Empty,
+        );
+        CallSpan { span }
+    }
+
+    pub fn record_request_size(&self, request_size: usize) {
+        self.span.record("request_size", request_size);
+    }
+
+    pub async fn instrument<F: Future>(&self, future: F) -> F::Output {
+        tracing::Instrument::instrument(future, self.span.clone()).await
+    }
+
+    pub fn record_result<U>(
+        &self,
+        result: &Result<ElongResponse<U>, ElongError>,
+        http_status: Option<u16>,
+        retries: u32,
+        elapsed: Duration,
+    ) {
+        if let Some(status) = http_status {
+            self.span.record("http_status", status);
+        }
+        self.span.record("retries", retries);
+        self.span.record("elapsed_ms", elapsed.as_millis() as u64);
+        match result {
+            Ok(response) => {
+                self.span.record("code", response.code.as_str());
+                if let Some(guid) = &response.guid {
+                    self.span.record("guid", guid.as_str());
+                }
+            }
+            Err(err) => {
+                if let Some(api_error) = err.api_error() {
+                    self.span.record("code", api_error.code.as_str());
+                    if let Some(guid) = &api_error.guid {
+                        self.span.record("guid", guid.as_str());
+                    }
+                }
+                self.span.record("error", tracing::field::display(err));
+            }
+        }
+    }
+}
+
+#[cfg(not(feature = "tracing"))]
+impl CallSpan {
+    pub fn new(_method: ApiMethod) -> Self {
+        CallSpan {}
+    }
+
+    pub fn record_request_size(&self, _request_size: usize) {}
+
+    pub async fn instrument<F: Future>(&self, future: F) -> F::Output {
+        future.await
+    }
+
+    pub fn record_result<U>(
+        &self,
+        _result: &Result<ElongResponse<U>, ElongError>,
+        _http_status: Option<u16>,
+        _retries: u32,
+        _elapsed: Duration,
+    ) {
+    }
+}
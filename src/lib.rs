@@ -5,6 +5,7 @@
 //! ## 模块概览
 //!
 //! - `elong`: 提供与 Elong 平台交互的具体实现。
+//! - `mock`: 本地模拟艺龙接口（需开启 `mock-server` 特性）。
 //! - `network`: 处理网络请求的底层模块。
 //! - `request`: 定义了所有请求类型。
 //! - `response`: 定义了所有响应类型。
@@ -40,7 +41,9 @@ use crate::request::{
 };
 
 pub mod elong;
-mod network;
+#[cfg(feature = "mock-server")]
+pub mod mock;
+pub mod network;
 pub mod request;
 pub mod response;
 mod types;
@@ -0,0 +1,6 @@
+//! 本地模拟艺龙接口
+//!
+//! 需要开启 `mock-server` 特性，可用于集成测试或本地联调，也可直接运行 `elong-mock` 二进制。
+
+pub mod orders;
+pub mod server;
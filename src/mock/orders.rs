@@ -0,0 +1,720 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use chrono::{DateTime, FixedOffset, NaiveDate, TimeZone, Utc};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
+use crate::{
+    elong::{
+        clock::{Clock, SystemClock},
+        endpoints::ApiMethod,
+    },
+    request::{
+        incr_order::IncrOrderRequest, order_cancel::OrderCancelRequest,
+        order_create::OrderCreateRequest, order_detail::OrderDetailRequest,
+        order_list::OrderListRequest, order_pay::OrderPayRequest,
+        order_pay_confirm::OrderPayConfirmRequest, order_promote::OrderPromoteRequest,
+    },
+    response::{
+        incr_order,
+        order_cancel::OrderCancelResponse,
+        order_create::OrderCreateResponse,
+        order_detail::{self, OrderDetailReponse},
+        order_list::{self, OrderListResponse},
+        order_pay::OrderPayResponse,
+        order_pay_confirm::OrderPayConfirmResponse,
+        order_promote::OrderPromoteResponse,
+    },
+};
+
+use super::server::MockResponse;
+
+/// 订单状态，与艺龙一致
+pub const STATUS_CONFIRMED: &str = "A";
+pub const STATUS_NO_SHOW: &str = "B";
+pub const STATUS_CHECKED_OUT: &str = "C";
+pub const STATUS_DELETED: &str = "D";
+pub const STATUS_CANCELLED: &str = "E";
+pub const STATUS_CHECKED_IN: &str = "F";
+pub const STATUS_NEW: &str = "N";
+pub const STATUS_FULL: &str = "O";
+pub const STATUS_REVIEWED: &str = "V";
+
+/// 支付状态，与 `hotel.incr.order` 的 `PayStatus` 一致
+pub const PAY_STATUS_NONE: i32 = -1;
+pub const PAY_STATUS_WAITING: i32 = 1;
+pub const PAY_STATUS_PROCESSING: i32 = 2;
+pub const PAY_STATUS_SUCCESS: i32 = 3;
+
+/// 模拟返回码，仅用于本模拟服务，错误信息含有 `ElongResultCode` 归类所用的关键字
+pub const CODE_INVALID_PARAMETER: &str = "H000001|请求参数错误";
+pub const CODE_ORDER_NOT_FOUND: &str = "H001030|订单不存在";
+pub const CODE_ORDER_NOT_CANCELABLE: &str = "H001031|订单当前状态不可取消";
+
+/// 不可取消的状态
+const FINAL_STATUSES: &[&str] = &[
+    STATUS_NO_SHOW,
+    STATUS_CHECKED_OUT,
+    STATUS_DELETED,
+    STATUS_CANCELLED,
+    STATUS_CHECKED_IN,
+    STATUS_FULL,
+];
+
+/// 订单列表分页大小
+const PAGE_SIZE: usize = 10;
+/// 预付订单的支付时限
+const PAYMENT_WINDOW: chrono::Duration = chrono::Duration::minutes(30);
+/// 最晚免费取消时间的基准：入住日 18:00（北京时间）
+const ARRIVAL_HOUR: u32 = 18;
+
+/// 订单流程模拟
+///
+/// 处理 `hotel.order.*` 与 `hotel.incr.order`：下单分配订单号，按时间表自动推进状态，
+/// 也可通过 [`OrderSimulator::set_status`] 手动设置；详情、列表、增量、取消（含罚金）、
+/// 支付、支付确认与催单的结果保持一致。时间取自 [`Clock`]，测试中可使用 `FixedClock`。
+pub struct OrderSimulator {
+    clock: Arc<dyn Clock>,
+    schedule: Vec<(Duration, String)>,
+    free_cancel_before: Duration,
+    late_cancel_penalty_rate: f64,
+    instant_confirm: bool,
+    room_names: HashMap<String, String>,
+    state: Mutex<Orders>,
+}
+
+struct Orders {
+    next_id: i64,
+    orders: BTreeMap<i64, SimOrder>,
+    events: Vec<OrderEvent>,
+}
+
+struct SimOrder {
+    request: OrderCreateRequest,
+    room_name: String,
+    created_at: DateTime<Utc>,
+    status: String,
+    pay_status: i32,
+    paid_amount: f64,
+    refund_amount: f64,
+    penalty: Option<f64>,
+    cancel_deadline: DateTime<Utc>,
+    schedule_step: usize,
+    manual: bool,
+    instant_confirm: bool,
+}
+
+/// 订单增量中的一条记录
+struct OrderEvent {
+    last_id: i64,
+    time: DateTime<Utc>,
+    order_id: i64,
+    status: String,
+    pay_status: i32,
+}
+
+impl Default for OrderSimulator {
+    fn default() -> Self {
+        OrderSimulator {
+            clock: Arc::new(SystemClock),
+            schedule: vec![
+                (Duration::from_secs(30), STATUS_REVIEWED.to_string()),
+                (Duration::from_secs(60), STATUS_CONFIRMED.to_string()),
+            ],
+            free_cancel_before: Duration::from_secs(24 * 3600),
+            late_cancel_penalty_rate: 1.0,
+            instant_confirm: false,
+            room_names: HashMap::new(),
+            state: Mutex::new(Orders {
+                next_id: 100_000_001,
+                orders: BTreeMap::new(),
+                events: Vec::new(),
+            }),
+        }
+    }
+}
+
+impl OrderSimulator {
+    /// 默认下单 30 秒后变为已审（V），60 秒后变为已确认（A）
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// 状态时间表，每项为距下单的时间与到达的状态，为空时只能手动推进
+    pub fn schedule(mut self, schedule: Vec<(Duration, &str)>) -> Self {
+        self.schedule = schedule
+            .into_iter()
+            .map(|(after, status)| (after, status.to_string()))
+            .collect();
+        self
+    }
+
+    /// 入住日 18:00 前多久之内取消需要罚金，默认 24 小时
+    pub fn free_cancel_before(mut self, before: Duration) -> Self {
+        self.free_cancel_before = before;
+        self
+    }
+
+    /// 超过免费取消时间后的罚金比例，按订单总价计算，默认全额
+    pub fn late_cancel_penalty_rate(mut self, rate: f64) -> Self {
+        self.late_cancel_penalty_rate = rate;
+        self
+    }
+
+    /// 订单是否即时确认
+    pub fn instant_confirm(mut self, instant_confirm: bool) -> Self {
+        self.instant_confirm = instant_confirm;
+        self
+    }
+
+    /// 房型名称，订单详情中返回，未设置的房型返回空字符串
+    pub fn room_name(mut self, room_type_id: impl Into<String>, name: impl Into<String>) -> Self {
+        self.room_names.insert(room_type_id.into(), name.into());
+        self
+    }
+
+    /// 第一个订单号
+    pub fn first_order_id(self, order_id: i64) -> Self {
+        self.state.lock().unwrap().next_id = order_id;
+        self
+    }
+
+    /// 手动设置订单状态，之后不再按时间表推进；订单不存在时返回 `false`
+    pub fn set_status(&self, order_id: i64, status: &str) -> bool {
+        let now = self.clock.now();
+        let mut state = self.state.lock().unwrap();
+        let Some(order) = state.orders.get_mut(&order_id) else {
+            return false;
+        };
+        order.manual = true;
+        order.status = status.to_string();
+        let pay_status = order.pay_status;
+        state.push_event(now, order_id, status, pay_status);
+        true
+    }
+
+    /// 订单当前状态
+    pub fn status(&self, order_id: i64) -> Option<String> {
+        let now = self.clock.now();
+        let mut state = self.state.lock().unwrap();
+        state.refresh(self, now);
+        state
+            .orders
+            .get(&order_id)
+            .map(|order| order.status.clone())
+    }
+
+    /// 所有订单号
+    pub fn order_ids(&self) -> Vec<i64> {
+        self.state.lock().unwrap().orders.keys().copied().collect()
+    }
+
+    /// 处理订单相关接口，其他接口返回 `None`
+    pub fn handle(&self, method: ApiMethod, request: &Value) -> Option<MockResponse> {
+        let now = self.clock.now();
+        let mut state = self.state.lock().unwrap();
+        state.refresh(self, now);
+        let response = match method {
+            ApiMethod::OrderCreate => parse(request).map(|req| self.create(&mut state, now, req)),
+            ApiMethod::OrderDetail => parse(request).map(|req| detail(&state, &req)),
+            ApiMethod::OrderList => parse(request).map(|req| list(&state, &req)),
+            ApiMethod::IncrOrder => parse(request).map(|req| incr(&state, &req)),
+            ApiMethod::OrderCancel => parse(request).map(|req| self.cancel(&mut state, now, &req)),
+            ApiMethod::OrderPay => parse(request).map(|req| pay(&mut state, now, &req)),
+            ApiMethod::OrderPayConfirm => {
+                parse(request).map(|req| pay_confirm(&mut state, now, &req))
+            }
+            ApiMethod::OrderPromote => {
+                parse(request).map(|req| self.promote(&mut state, now, &req))
+            }
+            _ => return None,
+        };
+        Some(response.unwrap_or_else(|message| {
+            MockResponse::api_error(format!("{CODE_INVALID_PARAMETER}，{message}"))
+        }))
+    }
+
+    fn create(
+        &self,
+        state: &mut Orders,
+        now: DateTime<Utc>,
+        req: OrderCreateRequest,
+    ) -> MockResponse {
+        if let Some(message) = validate(&req) {
+            return MockResponse::api_error(format!("{CODE_INVALID_PARAMETER}，{message}"));
+        }
+        // 合作伙伴订单号相同视为重复提交，返回已有订单
+        let existing = state
+            .orders
+            .iter()
+            .find(|(_, order)| {
+                order.request.affiliate_confirmation_id == req.affiliate_confirmation_id
+            })
+            .map(|(order_id, _)| *order_id);
+        let order_id = match existing {
+            Some(order_id) => order_id,
+            None => {
+                let order_id = state.next_id;
+                state.next_id += 1;
+                let prepay = req.payment_type.eq_ignore_ascii_case("Prepay");
+                let charged = req.is_guarantee_or_charged == Some(true);
+                let (pay_status, paid_amount) = match (prepay, charged) {
+                    (true, true) => (PAY_STATUS_SUCCESS, req.total_price),
+                    (true, false) => (PAY_STATUS_WAITING, 0.0),
+                    _ => (PAY_STATUS_NONE, 0.0),
+                };
+                let cancel_deadline = self.cancel_deadline(&req.arrival_date, now);
+                state.orders.insert(
+                    order_id,
+                    SimOrder {
+                        room_name: self
+                            .room_names
+                            .get(&req.room_type_id)
+                            .cloned()
+                            .unwrap_or_default(),
+                        request: req,
+                        created_at: now,
+                        status: STATUS_NEW.to_string(),
+                        pay_status,
+                        paid_amount,
+                        refund_amount: 0.0,
+                        penalty: None,
+                        cancel_deadline,
+                        schedule_step: 0,
+                        manual: false,
+                        instant_confirm: self.instant_confirm,
+                    },
+                );
+                state.push_event(now, order_id, STATUS_NEW, pay_status);
+                order_id
+            }
+        };
+        let order = &state.orders[&order_id];
+        ok(OrderCreateResponse {
+            order_id,
+            cancel_time: format_time(order.cancel_deadline),
+            currency_code: Some(order.request.currency_code.clone()),
+            is_instant_confirm: Some(order.instant_confirm),
+            payment_deadline_time: (order.pay_status == PAY_STATUS_WAITING)
+                .then(|| format_time(order.created_at + PAYMENT_WINDOW)),
+            ..Default::default()
+        })
+    }
+
+    fn cancel(
+        &self,
+        state: &mut Orders,
+        now: DateTime<Utc>,
+        req: &OrderCancelRequest,
+    ) -> MockResponse {
+        let Some(order) = state.orders.get_mut(&req.order_id) else {
+            return MockResponse::api_error(CODE_ORDER_NOT_FOUND);
+        };
+        if FINAL_STATUSES.contains(&order.status.as_str()) {
+            return MockResponse::api_error(CODE_ORDER_NOT_CANCELABLE);
+        }
+        let penalty = if now > order.cancel_deadline {
+            round2(order.request.total_price * self.late_cancel_penalty_rate)
+        } else {
+            0.0
+        };
+        // 0 不校验罚金；-1 有罚金时不取消；大于 0 时需与实际罚金一致
+        let accepted = match req.penalty_amount {
+            None => true,
+            Some(0.0) => true,
+            Some(expected) if expected < 0.0 => penalty == 0.0,
+            Some(expected) => (expected - penalty).abs() < 0.005,
+        };
+        if !accepted {
+            return ok(OrderCancelResponse {
+                successs: false,
+                penalty_amount: Some(penalty),
+            });
+        }
+        order.status = STATUS_CANCELLED.to_string();
+        order.penalty = Some(penalty);
+        order.refund_amount = round2((order.paid_amount - penalty).max(0.0));
+        let pay_status = order.pay_status;
+        state.push_event(now, req.order_id, STATUS_CANCELLED, pay_status);
+        ok(OrderCancelResponse {
+            successs: true,
+            penalty_amount: Some(penalty),
+        })
+    }
+
+    /// 催单：新单立即推进到时间表的下一个状态
+    fn promote(
+        &self,
+        state: &mut Orders,
+        now: DateTime<Utc>,
+        req: &OrderPromoteRequest,
+    ) -> MockResponse {
+        let Some(order) = state.orders.get_mut(&req.order_id) else {
+            return MockResponse::api_error(CODE_ORDER_NOT_FOUND);
+        };
+        if order.status == STATUS_NEW && !order.manual {
+            if let Some((_, status)) = self.schedule.get(order.schedule_step) {
+                order.status = status.clone();
+                order.schedule_step += 1;
+                let (status, pay_status) = (order.status.clone(), order.pay_status);
+                state.push_event(now, req.order_id, &status, pay_status);
+            }
+        }
+        ok(OrderPromoteResponse {
+            adjust_time: Some(format_time(now)),
+        })
+    }
+
+    /// 最晚免费取消时间：入住日 18:00（北京时间）减去 `free_cancel_before`
+    fn cancel_deadline(&self, arrival_date: &str, now: DateTime<Utc>) -> DateTime<Utc> {
+        NaiveDate::parse_from_str(arrival_date, "%Y-%m-%d")
+            .ok()
+            .and_then(|date| date.and_hms_opt(ARRIVAL_HOUR, 0, 0))
+            .and_then(|time| beijing().from_local_datetime(&time).single())
+            .map(|time| time.with_timezone(&Utc))
+            .and_then(|time| {
+                chrono::Duration::from_std(self.free_cancel_before)
+                    .ok()
+                    .map(|before| time - before)
+            })
+            .unwrap_or(now)
+    }
+}
+
+impl Orders {
+    fn push_event(&mut self, time: DateTime<Utc>, order_id: i64, status: &str, pay_status: i32) {
+        let last_id = self.events.last().map_or(1, |event| event.last_id + 1);
+        self.events.push(OrderEvent {
+            last_id,
+            time,
+            order_id,
+            status: status.to_string(),
+            pay_status,
+        });
+    }
+
+    /// 按时间表推进所有订单的状态
+    fn refresh(&mut self, simulator: &OrderSimulator, now: DateTime<Utc>) {
+        let mut changes = Vec::new();
+        for (order_id, order) in self.orders.iter_mut() {
+            if order.manual || FINAL_STATUSES.contains(&order.status.as_str()) {
+                continue;
+            }
+            while let Some((after, status)) = simulator.schedule.get(order.schedule_step) {
+                let due = chrono::Duration::from_std(*after)
+                    .map_or(now, |after| order.created_at + after);
+                if due > now {
+                    break;
+                }
+                order.status = status.clone();
+                order.schedule_step += 1;
+                changes.push((due, *order_id, status.clone(), order.pay_status));
+            }
+        }
+        changes.sort_by_key(|(due, order_id, _, _)| (*due, *order_id));
+        for (due, order_id, status, pay_status) in changes {
+            self.push_event(due, order_id, &status, pay_status);
+        }
+    }
+}
+
+fn detail(state: &Orders, req: &OrderDetailRequest) -> MockResponse {
+    let found = if req.order_id != 0 {
+        state.orders.get_key_value(&req.order_id)
+    } else {
+        state.orders.iter().find(|(_, order)| {
+            Some(&order.request.affiliate_confirmation_id) == req.affiliate_confirmation_id.as_ref()
+        })
+    };
+    let Some((order_id, order)) = found else {
+        return MockResponse::api_error(CODE_ORDER_NOT_FOUND);
+    };
+    let r = &order.request;
+    ok(OrderDetailReponse {
+        order_id: *order_id,
+        hotel_id: r.hotel_id.clone(),
+        room_id: r.room_id.clone(),
+        room_name: order.room_name.clone(),
+        room_type_id: r.room_type_id.clone(),
+        rate_plan_id: r.rate_plan_id,
+        arrival_date: r.arrival_date.clone(),
+        departure_date: r.departure_date.clone(),
+        status: order.status.clone(),
+        customer_type: r.customer_type.clone().unwrap_or_else(|| "All".to_string()),
+        payment_type: r.payment_type.clone(),
+        number_of_rooms: r.number_of_rooms,
+        number_of_customers: r.number_of_customers,
+        earliest_arrival_time: r.earliest_arrival_time.clone(),
+        latest_arrival_time: r.latest_arrival_time.clone(),
+        currency_code: r.currency_code.clone(),
+        total_price: r.total_price,
+        confirmation_type: r.confirmation_type.clone(),
+        note_to_hotel: r.note_to_hotel.clone(),
+        note_to_elong: r.note_to_elong.clone(),
+        penalty_to_customer: order.penalty,
+        penalty_currency_code: order.penalty.map(|_| r.currency_code.clone()),
+        is_refund: Some(order.refund_amount > 0.0),
+        creation_date: Some(format_time(order.created_at)),
+        is_cancelable: Some(!FINAL_STATUSES.contains(&order.status.as_str())),
+        cancel_time: format_time(order.cancel_deadline),
+        is_instant_confirm: Some(order.instant_confirm),
+        affiliate_confirmation_id: Some(r.affiliate_confirmation_id.clone()),
+        refund_amount: Some(order.refund_amount),
+        pay_amount: Some(order.paid_amount),
+        contact: order_detail::Contact {
+            name: r.contact.name.clone(),
+            email: r.contact.email.clone(),
+            mobile: Some(r.contact.mobile.clone()),
+            ..Default::default()
+        },
+        order_rooms: r
+            .order_rooms
+            .iter()
+            .map(|room| order_detail::OrderRoom {
+                customers: room
+                    .customers
+                    .iter()
+                    .map(|customer| order_detail::Customer {
+                        name: customer.name.clone(),
+                        ..Default::default()
+                    })
+                    .collect(),
+                room_no: None,
+            })
+            .collect(),
+        ..Default::default()
+    })
+}
+
+fn list(state: &Orders, req: &OrderListRequest) -> MockResponse {
+    let in_range = |value: &str, from: &Option<String>, to: &Option<String>| {
+        from.as_deref().is_none_or(|from| value >= from)
+            && to.as_deref().is_none_or(|to| value <= to)
+    };
+    let matched: Vec<_> = state
+        .orders
+        .iter()
+        .filter(|(_, order)| {
+            let r = &order.request;
+            req.hotel_id.as_ref().is_none_or(|id| *id == r.hotel_id)
+                && req
+                    .room_type_id
+                    .as_ref()
+                    .is_none_or(|id| *id == r.room_type_id)
+                && req.rate_plan_id.is_none_or(|id| id == r.rate_plan_id)
+                && req
+                    .status
+                    .as_ref()
+                    .is_none_or(|status| *status == order.status)
+                && req
+                    .mobile
+                    .as_ref()
+                    .is_none_or(|mobile| *mobile == r.contact.mobile)
+                && req.customer_name.as_ref().is_none_or(|name| {
+                    r.order_rooms
+                        .iter()
+                        .flat_map(|room| &room.customers)
+                        .any(|customer| customer.name == *name)
+                })
+                && in_range(
+                    &r.arrival_date,
+                    &req.arrival_date_from,
+                    &req.arrival_date_to,
+                )
+                && in_range(
+                    &r.departure_date,
+                    &req.departure_date_from,
+                    &req.departure_date_to,
+                )
+                && in_range(
+                    &format_time(order.created_at),
+                    &req.creation_time_from,
+                    &req.creation_time_to,
+                )
+        })
+        .collect();
+    let page = req.page_index.max(1) as usize - 1;
+    let orders = matched
+        .iter()
+        .skip(page * PAGE_SIZE)
+        .take(PAGE_SIZE)
+        .map(|(order_id, order)| {
+            let r = &order.request;
+            order_list::Order {
+                order_id: **order_id,
+                status: order.status.clone(),
+                total_price: r.total_price,
+                currency_code: r.currency_code.clone(),
+                hotel_id: r.hotel_id.clone(),
+                room_type_id: r.room_type_id.clone(),
+                rate_plan_id: r.rate_plan_id,
+                arrival_date: r.arrival_date.clone(),
+                departure_date: r.departure_date.clone(),
+                customer_type: r.customer_type.clone().unwrap_or_else(|| "All".to_string()),
+                number_of_rooms: r.number_of_rooms,
+                number_of_customers: r.number_of_customers,
+                payment_type: r.payment_type.clone(),
+                earliest_arrival_time: r.earliest_arrival_time.clone(),
+                latest_arrival_time: r.latest_arrival_time.clone(),
+                confirmation_type: r.confirmation_type.clone(),
+                note_to_hotel: r.note_to_hotel.clone(),
+                note_to_elong: r.note_to_elong.clone(),
+                ..Default::default()
+            }
+        })
+        .collect();
+    ok(OrderListResponse {
+        count: matched.len() as i32,
+        orders: Some(orders),
+    })
+}
+
+fn incr(state: &Orders, req: &IncrOrderRequest) -> MockResponse {
+    let count = req.count.unwrap_or(1000).clamp(1, 5000) as usize;
+    let orders = state
+        .events
+        .iter()
+        .filter(|event| event.last_id > req.last_id)
+        .take(count)
+        .filter_map(|event| {
+            let order = state.orders.get(&event.order_id)?;
+            Some(incr_order::Order {
+                last_id: event.last_id,
+                time: format_time(event.time),
+                order_id: event.order_id,
+                status: event.status.clone(),
+                arrival_date: order.request.arrival_date.clone(),
+                departure_date: order.request.departure_date.clone(),
+                total_price: order.request.total_price,
+                number_of_rooms: order.request.number_of_rooms,
+                affiliate_confirmation_id: Some(order.request.affiliate_confirmation_id.clone()),
+                all_refund_amount: Some(order.refund_amount),
+                pay_status: Some(event.pay_status),
+                is_instant_confirm: Some(order.instant_confirm),
+            })
+        })
+        .collect();
+    ok(incr_order::IncrOrderResponse {
+        orders: Some(orders),
+    })
+}
+
+fn pay(state: &mut Orders, now: DateTime<Utc>, req: &OrderPayRequest) -> MockResponse {
+    let Some(order) = state.orders.get_mut(&req.order_id) else {
+        return MockResponse::api_error(CODE_ORDER_NOT_FOUND);
+    };
+    if let Some(notes) = payment_error(order, req.amount, PAY_STATUS_WAITING) {
+        return ok(OrderPayResponse {
+            is_success: false,
+            notes: Some(notes.to_string()),
+        });
+    }
+    // 已担保或已付款时直接成功，否则等待 `hotel.order.pay.confirm`
+    let (pay_status, notes) = if req.is_guarantee_or_charged {
+        order.paid_amount = req.amount;
+        (PAY_STATUS_SUCCESS, None)
+    } else {
+        (
+            PAY_STATUS_PROCESSING,
+            Some("请调用 hotel.order.pay.confirm 确认支付".to_string()),
+        )
+    };
+    order.pay_status = pay_status;
+    let status = order.status.clone();
+    state.push_event(now, req.order_id, &status, pay_status);
+    ok(OrderPayResponse {
+        is_success: true,
+        notes,
+    })
+}
+
+fn pay_confirm(
+    state: &mut Orders,
+    now: DateTime<Utc>,
+    req: &OrderPayConfirmRequest,
+) -> MockResponse {
+    let Some(order) = state.orders.get_mut(&req.order_id) else {
+        return MockResponse::api_error(CODE_ORDER_NOT_FOUND);
+    };
+    let error = payment_error(order, req.amount, PAY_STATUS_PROCESSING).or_else(|| {
+        req.sms_code
+            .trim()
+            .is_empty()
+            .then_some("短信验证码不能为空")
+    });
+    if let Some(notes) = error {
+        return ok(OrderPayConfirmResponse {
+            is_success: false,
+            notes: Some(notes.to_string()),
+        });
+    }
+    order.pay_status = PAY_STATUS_SUCCESS;
+    order.paid_amount = req.amount;
+    let status = order.status.clone();
+    state.push_event(now, req.order_id, &status, PAY_STATUS_SUCCESS);
+    ok(OrderPayConfirmResponse {
+        is_success: true,
+        notes: None,
+    })
+}
+
+/// 支付前的校验，失败时返回原因
+fn payment_error(order: &SimOrder, amount: f64, expected: i32) -> Option<&'static str> {
+    if order.status == STATUS_CANCELLED {
+        Some("订单已取消")
+    } else if order.pay_status == PAY_STATUS_SUCCESS {
+        Some("订单已支付")
+    } else if order.pay_status != expected {
+        Some("订单当前支付状态不允许该操作")
+    } else if (amount - order.request.total_price).abs() >= 0.005 {
+        Some("支付金额与订单金额不一致")
+    } else {
+        None
+    }
+}
+
+fn validate(req: &OrderCreateRequest) -> Option<&'static str> {
+    if req.affiliate_confirmation_id.is_empty() {
+        Some("AffiliateConfirmationId 不能为空")
+    } else if req.number_of_rooms <= 0 || req.order_rooms.is_empty() {
+        Some("房间数量必须大于 0")
+    } else if req.total_price <= 0.0 {
+        Some("总价必须大于 0")
+    } else if req.arrival_date >= req.departure_date {
+        Some("离店日期必须晚于入住日期")
+    } else {
+        None
+    }
+}
+
+fn parse<T: DeserializeOwned>(request: &Value) -> Result<T, String> {
+    serde_json::from_value(request.clone()).map_err(|e| e.to_string())
+}
+
+fn ok(result: impl Serialize) -> MockResponse {
+    MockResponse::ok(serde_json::to_value(result).unwrap_or(Value::Null))
+}
+
+fn round2(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
+}
+
+fn beijing() -> FixedOffset {
+    FixedOffset::east_opt(8 * 3600).unwrap()
+}
+
+/// 艺龙接口使用北京时间 `yyyy-MM-dd HH:mm:ss`
+fn format_time(time: DateTime<Utc>) -> String {
+    time.with_timezone(&beijing())
+        .format("%Y-%m-%d %H:%M:%S")
+        .to_string()
+}
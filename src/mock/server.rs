@@ -0,0 +1,473 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    fs,
+    net::SocketAddr,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use async_compression::tokio::bufread::GzipEncoder;
+use serde_json::{json, Value};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+    task::JoinHandle,
+};
+
+use crate::{
+    elong::{credentials::Credentials, endpoints::ApiMethod, error::ElongError},
+    network::transport::HttpMethod,
+    request::api_request::ApiSignedRequest,
+};
+
+use super::orders::OrderSimulator;
+
+/// 请求头最大长度
+const MAX_HEAD_SIZE: usize = 64 * 1024;
+
+/// 请求体最大长度
+const MAX_BODY_SIZE: usize = 1024 * 1024;
+
+/// 接受连接失败（如文件描述符耗尽）后的等待时间，避免空转
+const ACCEPT_BACKOFF: Duration = Duration::from_millis(100);
+
+/// 模拟返回码，仅用于本模拟服务，不保证艺龙线上返回相同的错误码；
+/// 错误信息含有 `ElongResultCode` 归类所用的关键字
+pub const CODE_MISSING_PARAMETER: &str = "H000001|缺少参数";
+pub const CODE_TIMESTAMP_EXPIRED: &str = "H000002|时间戳过期";
+pub const CODE_UNKNOWN_USER: &str = "H000003|用户名不存在";
+pub const CODE_SIGNATURE_INVALID: &str = "H000004|签名错误";
+pub const CODE_UNKNOWN_METHOD: &str = "H000005|方法不存在";
+
+/// 模拟响应
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MockResponse {
+    /// HTTP 状态码
+    pub status: u16,
+    /// 响应体
+    pub body: String,
+    /// 额外延迟，叠加在全局延迟之上
+    pub latency: Option<Duration>,
+    /// 是否压缩，`None` 时使用全局设置
+    pub gzip: Option<bool>,
+}
+
+impl MockResponse {
+    /// 原样返回的响应体
+    pub fn json(body: impl Into<String>) -> Self {
+        MockResponse {
+            status: 200,
+            body: body.into(),
+            latency: None,
+            gzip: None,
+        }
+    }
+
+    /// 成功响应，`Result` 为给定的值
+    pub fn ok(result: Value) -> Self {
+        Self::json(json!({"Code": "0", "Result": result, "Guid": guid()}).to_string())
+    }
+
+    /// 业务错误，如 `H001022|房量不足`
+    pub fn api_error(code: impl Into<String>) -> Self {
+        Self::json(json!({"Code": code.into(), "Result": null, "Guid": guid()}).to_string())
+    }
+
+    /// HTTP 错误，如 502 与网关返回的 HTML 页面
+    pub fn http_error(status: u16, body: impl Into<String>) -> Self {
+        MockResponse {
+            status,
+            ..Self::json(body)
+        }
+    }
+
+    pub fn with_latency(mut self, latency: Duration) -> Self {
+        self.latency = Some(latency);
+        self
+    }
+
+    pub fn with_gzip(mut self, gzip: bool) -> Self {
+        self.gzip = Some(gzip);
+        self
+    }
+}
+
+/// 模拟服务器收到的请求
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReceivedRequest {
+    /// 方法名
+    pub method: String,
+    /// 账户名
+    pub user: String,
+    /// 请求方式
+    pub http_method: HttpMethod,
+    /// 业务参数，即 `data` 中的 `Request`
+    pub request: Value,
+    /// 签名是否正确
+    pub signature_valid: bool,
+}
+
+/// 模拟服务器构建器
+///
+/// 未注册账户时不校验签名；同一接口多次注册响应时按顺序返回，最后一个重复使用。
+#[derive(Default)]
+pub struct MockServerBuilder {
+    addr: Option<SocketAddr>,
+    accounts: HashMap<String, Credentials>,
+    fixtures: HashMap<String, VecDeque<MockResponse>>,
+    fixture_dir: Option<PathBuf>,
+    latency: Duration,
+    gzip: bool,
+    max_clock_skew: Option<Duration>,
+    orders: Option<Arc<OrderSimulator>>,
+}
+
+impl MockServerBuilder {
+    /// 监听地址，默认 `127.0.0.1` 上的随机端口
+    pub fn addr(mut self, addr: SocketAddr) -> Self {
+        self.addr = Some(addr);
+        self
+    }
+
+    /// 注册账户，注册后校验签名
+    pub fn account(mut self, credentials: Credentials) -> Self {
+        self.accounts
+            .insert(credentials.username.clone(), credentials);
+        self
+    }
+
+    /// 为接口注册响应
+    pub fn fixture(mut self, method: impl Into<String>, response: MockResponse) -> Self {
+        self.fixtures
+            .entry(method.into())
+            .or_default()
+            .push_back(response);
+        self
+    }
+
+    /// 响应目录，未注册响应的接口读取其中的 `<方法名>.json`
+    pub fn fixture_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.fixture_dir = Some(dir.into());
+        self
+    }
+
+    /// 每个请求的固定延迟
+    pub fn latency(mut self, latency: Duration) -> Self {
+        self.latency = latency;
+        self
+    }
+
+    /// 客户端支持时以 gzip 压缩响应
+    pub fn gzip(mut self, gzip: bool) -> Self {
+        self.gzip = gzip;
+        self
+    }
+
+    /// 时间戳与服务器时间相差超过该值时返回时间戳过期
+    pub fn max_clock_skew(mut self, skew: Duration) -> Self {
+        self.max_clock_skew = Some(skew);
+        self
+    }
+
+    /// 由订单模拟处理 `hotel.order.*` 与 `hotel.incr.order`，已注册响应的接口仍优先返回注册的响应
+    pub fn order_simulator(mut self, orders: Arc<OrderSimulator>) -> Self {
+        self.orders = Some(orders);
+        self
+    }
+
+    /// 启动服务器
+    pub async fn start(self) -> Result<MockServer, ElongError> {
+        let addr = self
+            .addr
+            .unwrap_or_else(|| SocketAddr::from(([127, 0, 0, 1], 0)));
+        let listener = TcpListener::bind(addr).await?;
+        let addr = listener.local_addr()?;
+        let state = Arc::new(State {
+            accounts: self.accounts,
+            fixtures: Mutex::new(self.fixtures),
+            fixture_dir: self.fixture_dir,
+            latency: self.latency,
+            gzip: self.gzip,
+            max_clock_skew: self.max_clock_skew,
+            orders: self.orders,
+            requests: Mutex::new(Vec::new()),
+        });
+        let task = tokio::spawn(serve(listener, state.clone()));
+        Ok(MockServer { addr, state, task })
+    }
+}
+
+/// 模拟艺龙接口的 HTTP 服务器，释放时停止
+pub struct MockServer {
+    addr: SocketAddr,
+    state: Arc<State>,
+    task: JoinHandle<()>,
+}
+
+impl MockServer {
+    pub fn builder() -> MockServerBuilder {
+        MockServerBuilder::default()
+    }
+
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// 接口地址，可直接用于 `ElongServiceBuilder::base_url`
+    pub fn url(&self) -> String {
+        format!("http://{}/rest", self.addr)
+    }
+
+    /// 已收到的请求
+    pub fn requests(&self) -> Vec<ReceivedRequest> {
+        self.state.requests.lock().unwrap().clone()
+    }
+
+    /// 运行中追加响应
+    pub fn add_fixture(&self, method: impl Into<String>, response: MockResponse) {
+        self.state
+            .fixtures
+            .lock()
+            .unwrap()
+            .entry(method.into())
+            .or_default()
+            .push_back(response);
+    }
+
+    /// 持续运行直到任务结束，供 `elong-mock` 二进制使用
+    pub async fn run(mut self) {
+        let _ = (&mut self.task).await;
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+struct State {
+    accounts: HashMap<String, Credentials>,
+    fixtures: Mutex<HashMap<String, VecDeque<MockResponse>>>,
+    fixture_dir: Option<PathBuf>,
+    latency: Duration,
+    gzip: bool,
+    max_clock_skew: Option<Duration>,
+    orders: Option<Arc<OrderSimulator>>,
+    requests: Mutex<Vec<ReceivedRequest>>,
+}
+
+impl State {
+    /// 校验请求并分发到对应接口的响应
+    fn dispatch(&self, params: &str, http_method: HttpMethod) -> MockResponse {
+        let Some(signed) = ApiSignedRequest::from_params(params) else {
+            return MockResponse::api_error(CODE_MISSING_PARAMETER);
+        };
+        let payload: Value = serde_json::from_str(&signed.data).unwrap_or(Value::Null);
+        let account = self.accounts.get(&signed.user);
+        let signature_valid = account.is_some_and(|credentials| {
+            signed.verify(
+                credentials.app_key.expose(),
+                credentials.app_secret.expose(),
+            )
+        });
+        self.requests.lock().unwrap().push(ReceivedRequest {
+            method: signed.method.clone(),
+            user: signed.user.clone(),
+            http_method,
+            request: payload.get("Request").cloned().unwrap_or(Value::Null),
+            signature_valid,
+        });
+
+        if !self.accounts.is_empty() {
+            if account.is_none() {
+                return MockResponse::api_error(CODE_UNKNOWN_USER);
+            }
+            if !signature_valid {
+                return MockResponse::api_error(CODE_SIGNATURE_INVALID);
+            }
+        }
+        if let Some(skew) = self.max_clock_skew {
+            let now = chrono::Utc::now().timestamp();
+            let expired = signed
+                .timestamp
+                .parse::<i64>()
+                .map_or(true, |timestamp| timestamp.abs_diff(now) > skew.as_secs());
+            if expired {
+                return MockResponse::api_error(CODE_TIMESTAMP_EXPIRED);
+            }
+        }
+        let request = payload.get("Request").cloned().unwrap_or(Value::Null);
+        self.fixture(&signed.method, &request).unwrap_or_else(|| {
+            MockResponse::api_error(format!("{CODE_UNKNOWN_METHOD} {}", signed.method))
+        })
+    }
+
+    /// 依次查找注册的响应、订单模拟与响应目录
+    fn fixture(&self, method: &str, request: &Value) -> Option<MockResponse> {
+        {
+            let mut fixtures = self.fixtures.lock().unwrap();
+            if let Some(responses) = fixtures.get_mut(method) {
+                if responses.len() > 1 {
+                    return responses.pop_front();
+                }
+                if let Some(response) = responses.front() {
+                    return Some(response.clone());
+                }
+            }
+        }
+        if let Some(response) = self
+            .orders
+            .as_ref()
+            .and_then(|orders| orders.handle(ApiMethod::builtin(method)?, request))
+        {
+            return Some(response);
+        }
+        // 接口名来自请求，不允许跳出响应目录
+        if method.contains(['/', '\\']) || method.contains("..") {
+            return None;
+        }
+        let path = self.fixture_dir.as_ref()?.join(format!("{method}.json"));
+        fs::read_to_string(path).ok().map(MockResponse::json)
+    }
+}
+
+async fn serve(listener: TcpListener, state: Arc<State>) {
+    loop {
+        let stream = match listener.accept().await {
+            Ok((stream, _)) => stream,
+            Err(e) => {
+                log::warn!("mock server accept error: {e}");
+                tokio::time::sleep(ACCEPT_BACKOFF).await;
+                continue;
+            }
+        };
+        let state = state.clone();
+        tokio::spawn(async move {
+            if let Err(e) = handle(stream, &state).await {
+                log::debug!("mock server connection error: {e}");
+            }
+        });
+    }
+}
+
+/// 处理单个连接，每个连接只处理一个请求
+async fn handle(mut stream: TcpStream, state: &State) -> Result<(), ElongError> {
+    let mut buf = Vec::new();
+    let head_end = loop {
+        if let Some(pos) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+            break pos + 4;
+        }
+        if buf.len() > MAX_HEAD_SIZE {
+            return write_response(&mut stream, &MockResponse::http_error(431, ""), false).await;
+        }
+        let mut chunk = [0u8; 4096];
+        let n = stream.read(&mut chunk).await?;
+        if n == 0 {
+            return Ok(());
+        }
+        buf.extend_from_slice(&chunk[..n]);
+    };
+
+    let head = String::from_utf8_lossy(&buf[..head_end]).into_owned();
+    let mut lines = head.split("\r\n");
+    let request_line = lines.next().unwrap_or_default();
+    let mut parts = request_line.split_whitespace();
+    let verb = parts.next().unwrap_or_default().to_string();
+    let target = parts.next().unwrap_or_default().to_string();
+    let mut content_length = 0;
+    let mut accepts_gzip = false;
+    for line in lines {
+        let Some((name, value)) = line.split_once(':') else {
+            continue;
+        };
+        match name.trim().to_ascii_lowercase().as_str() {
+            "content-length" => content_length = value.trim().parse().unwrap_or(0),
+            "accept-encoding" => accepts_gzip = value.contains("gzip"),
+            _ => {}
+        }
+    }
+    if content_length > MAX_BODY_SIZE {
+        return write_response(&mut stream, &MockResponse::http_error(413, ""), false).await;
+    }
+    let mut body = buf[head_end..].to_vec();
+    while body.len() < content_length {
+        let mut chunk = vec![0u8; content_length - body.len()];
+        let n = stream.read(&mut chunk).await?;
+        if n == 0 {
+            break;
+        }
+        body.extend_from_slice(&chunk[..n]);
+    }
+
+    let (http_method, params) = if verb.eq_ignore_ascii_case("POST") {
+        (
+            HttpMethod::Post,
+            String::from_utf8_lossy(&body).into_owned(),
+        )
+    } else {
+        let query = target.split_once('?').map(|(_, q)| q).unwrap_or_default();
+        (HttpMethod::Get, query.to_string())
+    };
+    let response = state.dispatch(&params, http_method);
+
+    let latency = state.latency + response.latency.unwrap_or_default();
+    if !latency.is_zero() {
+        tokio::time::sleep(latency).await;
+    }
+    let gzip = accepts_gzip && response.gzip.unwrap_or(state.gzip);
+    write_response(&mut stream, &response, gzip).await
+}
+
+async fn write_response(
+    stream: &mut TcpStream,
+    response: &MockResponse,
+    gzip: bool,
+) -> Result<(), ElongError> {
+    let mut body = response.body.as_bytes().to_vec();
+    let mut head = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nDate: {}\r\nConnection: close\r\n",
+        response.status,
+        reason(response.status),
+        if response.body.trim_start().starts_with('<') {
+            "text/html; charset=utf-8"
+        } else {
+            "application/json; charset=utf-8"
+        },
+        chrono::Utc::now().to_rfc2822(),
+    );
+    if gzip {
+        let mut compressed = Vec::new();
+        GzipEncoder::new(body.as_slice())
+            .read_to_end(&mut compressed)
+            .await?;
+        body = compressed;
+        head.push_str("Content-Encoding: gzip\r\n");
+    }
+    head.push_str(&format!("Content-Length: {}\r\n\r\n", body.len()));
+    stream.write_all(head.as_bytes()).await?;
+    stream.write_all(&body).await?;
+    stream.shutdown().await?;
+    Ok(())
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        413 => "Payload Too Large",
+        431 => "Request Header Fields Too Large",
+        500 => "Internal Server Error",
+        502 => "Bad Gateway",
+        503 => "Service Unavailable",
+        504 => "Gateway Timeout",
+        _ => "Unknown",
+    }
+}
+
+fn guid() -> String {
+    use std::sync::atomic::{AtomicU64, Ordering};
+    static NEXT: AtomicU64 = AtomicU64::new(1);
+    format!("mock-{}", NEXT.fetch_add(1, Ordering::Relaxed))
+}
@@ -0,0 +1,292 @@
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tokio::{fs, sync::Mutex};
+
+use crate::{
+    elong::{error::ElongError, redact::redact_value},
+    request::api_request::ApiSignedRequest,
+};
+
+use super::transport::{HttpMethod, Transport, TransportResponse};
+
+/// 录制文件中替代账户名与签名的占位符
+const SCRUBBED: &str = "***";
+
+/// 录制与回放模式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CassetteMode {
+    /// 转发到真实传输层，并把请求与响应写入录制文件
+    Record,
+    /// 只从录制文件返回响应，不发出任何网络请求
+    Replay,
+}
+
+/// 录制/回放传输层
+///
+/// 每个接口对应目录下的一个录制文件，如 `hotel.incr.id.json`，内容为交互列表。
+/// 写入前会去掉账户名与签名，并对请求与响应中的个人信息脱敏；appKey 与 appSecret 不参与录制。
+/// 回放时按接口名与规范化后的请求 JSON（键排序、脱敏、去掉忽略的字段）匹配。
+///
+/// ```no_run
+/// use std::sync::Arc;
+/// use elong_sdk::network::cassette::CassetteTransport;
+/// use elong_sdk::elong::service::ElongService;
+///
+/// let service = ElongService::builder()
+///     .credentials("user", "app_key", "app_secret")
+///     .transport(Arc::new(CassetteTransport::replay("tests/fixtures")))
+///     .build()
+///     .unwrap();
+/// ```
+pub struct CassetteTransport {
+    dir: PathBuf,
+    mode: CassetteMode,
+    inner: Option<Arc<dyn Transport>>,
+    ignored_fields: HashSet<String>,
+    cassettes: Mutex<HashMap<String, Vec<Interaction>>>,
+}
+
+/// 一次录制的交互
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Interaction {
+    pub request: RecordedRequest,
+    pub response: RecordedResponse,
+}
+
+/// 录制的请求，账户名与签名已替换为 `***`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedRequest {
+    /// 方法名
+    pub method: String,
+    /// 请求方式，`GET` 或 `POST`
+    pub http_method: String,
+    /// 格式
+    pub format: String,
+    /// 账户名
+    pub user: String,
+    /// 签名
+    pub signature: String,
+    /// 规范化后的请求数据
+    pub data: Value,
+}
+
+/// 录制的原始响应
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    /// HTTP 状态码
+    pub status: Option<u16>,
+    /// 响应体（已解压），JSON 响应中的个人信息已脱敏
+    pub body: String,
+}
+
+impl CassetteTransport {
+    /// 录制模式，请求经 `inner` 发出
+    pub fn record(dir: impl Into<PathBuf>, inner: Arc<dyn Transport>) -> Self {
+        CassetteTransport {
+            dir: dir.into(),
+            mode: CassetteMode::Record,
+            inner: Some(inner),
+            ignored_fields: HashSet::new(),
+            cassettes: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// 回放模式
+    pub fn replay(dir: impl Into<PathBuf>) -> Self {
+        CassetteTransport {
+            dir: dir.into(),
+            mode: CassetteMode::Replay,
+            inner: None,
+            ignored_fields: HashSet::new(),
+            cassettes: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// 匹配时忽略的字段，如随当前日期变化的 `ArrivalDate`，任意层级均生效
+    pub fn ignore_field(mut self, field: impl Into<String>) -> Self {
+        self.ignored_fields.insert(field.into());
+        self
+    }
+
+    pub fn mode(&self) -> CassetteMode {
+        self.mode
+    }
+
+    /// 指定接口的录制文件路径
+    pub fn path_for(&self, method: &str) -> PathBuf {
+        self.dir.join(format!("{method}.json"))
+    }
+
+    /// 规范化请求数据：解析为 JSON（键有序）并脱敏
+    fn normalize(&self, data: &str) -> Value {
+        match serde_json::from_str::<Value>(data) {
+            Ok(mut value) => {
+                redact_value(&mut value);
+                value
+            }
+            Err(_) => Value::String(data.to_string()),
+        }
+    }
+
+    /// 去掉忽略的字段后比较请求数据
+    fn matches(&self, recorded: &Value, data: &Value) -> bool {
+        if self.ignored_fields.is_empty() {
+            return recorded == data;
+        }
+        let (mut recorded, mut data) = (recorded.clone(), data.clone());
+        remove_fields(&mut recorded, &self.ignored_fields);
+        remove_fields(&mut data, &self.ignored_fields);
+        recorded == data
+    }
+
+    fn recorded_request(
+        &self,
+        request: &ApiSignedRequest,
+        http_method: HttpMethod,
+    ) -> RecordedRequest {
+        RecordedRequest {
+            method: request.method.clone(),
+            http_method: match http_method {
+                HttpMethod::Get => "GET",
+                HttpMethod::Post => "POST",
+            }
+            .to_string(),
+            format: request.format.clone(),
+            user: SCRUBBED.to_string(),
+            signature: SCRUBBED.to_string(),
+            data: self.normalize(&request.data),
+        }
+    }
+
+    async fn replay_response(
+        &self,
+        recorded: &RecordedRequest,
+    ) -> Result<TransportResponse, ElongError> {
+        let mut cassettes = self.cassettes.lock().await;
+        let interactions = load(&mut cassettes, &self.path_for(&recorded.method)).await?;
+        let interaction = interactions
+            .iter()
+            .find(|interaction| self.matches(&interaction.request.data, &recorded.data))
+            .ok_or_else(|| {
+                ElongError::ConfigError(format!(
+                    "No recorded interaction for {} matching {}",
+                    recorded.method, recorded.data
+                ))
+            })?;
+        let response = &interaction.response;
+        let mut transport_response = TransportResponse::new(response.body.clone());
+        if let Some(status) = response.status {
+            transport_response = transport_response.with_status(status);
+        }
+        Ok(transport_response)
+    }
+
+    async fn record_interaction(
+        &self,
+        recorded: RecordedRequest,
+        response: &TransportResponse,
+    ) -> Result<(), ElongError> {
+        let path = self.path_for(&recorded.method);
+        let mut cassettes = self.cassettes.lock().await;
+        let interactions = load(&mut cassettes, &path).await?;
+        let interaction = Interaction {
+            response: RecordedResponse {
+                status: response.status,
+                body: redact_body(&response.body),
+            },
+            request: recorded,
+        };
+        match interactions
+            .iter_mut()
+            .find(|existing| self.matches(&existing.request.data, &interaction.request.data))
+        {
+            Some(existing) => *existing = interaction,
+            None => interactions.push(interaction),
+        }
+        let content = serde_json::to_vec_pretty(interactions)?;
+        fs::create_dir_all(&self.dir).await?;
+        fs::write(&path, content).await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Transport for CassetteTransport {
+    async fn send(
+        &self,
+        url: &str,
+        request: &ApiSignedRequest,
+        http_method: HttpMethod,
+    ) -> Result<TransportResponse, ElongError> {
+        self.send_with_headers(url, request, http_method, &[]).await
+    }
+
+    async fn send_with_headers(
+        &self,
+        url: &str,
+        request: &ApiSignedRequest,
+        http_method: HttpMethod,
+        headers: &[(String, String)],
+    ) -> Result<TransportResponse, ElongError> {
+        let recorded = self.recorded_request(request, http_method);
+        match (&self.mode, &self.inner) {
+            (CassetteMode::Record, Some(inner)) => {
+                let response = inner
+                    .send_with_headers(url, request, http_method, headers)
+                    .await?;
+                self.record_interaction(recorded, &response).await?;
+                Ok(response)
+            }
+            _ => self.replay_response(&recorded).await,
+        }
+    }
+}
+
+/// 读取录制文件，已读取过的直接返回缓存；录制模式下文件不存在视为空
+async fn load<'a>(
+    cassettes: &'a mut HashMap<String, Vec<Interaction>>,
+    path: &Path,
+) -> Result<&'a mut Vec<Interaction>, ElongError> {
+    let key = path.to_string_lossy().into_owned();
+    if !cassettes.contains_key(&key) {
+        let interactions = match fs::read(path).await {
+            Ok(content) => serde_json::from_slice(&content)?,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+            Err(err) => return Err(err.into()),
+        };
+        cassettes.insert(key.clone(), interactions);
+    }
+    Ok(cassettes.get_mut(&key).unwrap())
+}
+
+/// 对 JSON 响应体脱敏，非 JSON 响应原样保留
+fn redact_body(body: &[u8]) -> String {
+    match serde_json::from_slice::<Value>(body) {
+        Ok(mut value) => {
+            redact_value(&mut value);
+            value.to_string()
+        }
+        Err(_) => String::from_utf8_lossy(body).into_owned(),
+    }
+}
+
+fn remove_fields(value: &mut Value, fields: &HashSet<String>) {
+    match value {
+        Value::Object(map) => {
+            map.retain(|key, _| !fields.contains(key));
+            map.values_mut()
+                .for_each(|value| remove_fields(value, fields));
+        }
+        Value::Array(values) => values
+            .iter_mut()
+            .for_each(|value| remove_fields(value, fields)),
+        _ => {}
+    }
+}
@@ -0,0 +1,269 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    io,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use async_compression::tokio::bufread::{GzipDecoder, GzipEncoder};
+use async_trait::async_trait;
+use serde_json::json;
+use tokio::io::AsyncReadExt;
+
+use crate::{
+    elong::{endpoints::ApiMethod, error::ElongError},
+    request::api_request::ApiSignedRequest,
+};
+
+use super::transport::{HttpMethod, Transport, TransportResponse};
+
+/// 网关返回的 HTML 错误页
+const HTML_PAGE: &str = "<html><head><title>502 Bad Gateway</title></head>\
+<body><center><h1>502 Bad Gateway</h1></center></body></html>";
+
+/// 注入的故障
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fault {
+    /// 等待指定时间后超时，请求未到达下游
+    Timeout(Duration),
+    /// 请求已由下游处理，等待指定时间后超时，响应丢失
+    TimeoutAfterSend(Duration),
+    /// 连接被重置，请求未到达下游
+    ConnectionReset,
+    /// 下游响应的 gzip 内容被截断
+    TruncatedGzip,
+    /// HTTP 错误状态码，如 502
+    HttpStatus(u16),
+    /// 状态码为 200 的 HTML 错误页
+    HtmlPage,
+    /// 艺龙业务错误，格式同 `Code`，如 `H000997|系统繁忙`
+    ApiError(String),
+}
+
+/// 故障注入传输层
+///
+/// 包装任意传输层，按接口注入故障：[`FaultTransport::fail_next`] 按顺序注入指定故障，
+/// [`FaultTransport::random`] 按概率随机注入。随机数由种子决定，同一种子与调用顺序下结果可复现。
+///
+/// ```no_run
+/// use std::sync::Arc;
+/// use elong_sdk::elong::{endpoints::ApiMethod, service::ElongService};
+/// use elong_sdk::network::fault::{Fault, FaultTransport};
+///
+/// let service = ElongService::builder()
+///     .wrap_transport(|inner| {
+///         Arc::new(
+///             FaultTransport::new(inner, 42)
+///                 .random_all(0.1, vec![Fault::ConnectionReset, Fault::HttpStatus(502)]),
+///         )
+///     })
+///     .build()
+///     .unwrap();
+/// ```
+pub struct FaultTransport {
+    inner: Arc<dyn Transport>,
+    rng: Mutex<SplitMix64>,
+    random: Vec<RandomFaults>,
+    scripted: Mutex<HashMap<ApiMethod, VecDeque<Fault>>>,
+    injected: Mutex<Vec<(ApiMethod, Fault)>>,
+}
+
+/// 按概率注入的故障，`method` 为 `None` 时对所有接口生效
+struct RandomFaults {
+    method: Option<ApiMethod>,
+    probability: f64,
+    faults: Vec<Fault>,
+}
+
+impl FaultTransport {
+    pub fn new(inner: Arc<dyn Transport>, seed: u64) -> Self {
+        FaultTransport {
+            inner,
+            rng: Mutex::new(SplitMix64(seed)),
+            random: Vec::new(),
+            scripted: Mutex::new(HashMap::new()),
+            injected: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// 指定接口的每次请求以 `probability` 的概率注入 `faults` 中的一个
+    ///
+    /// 概率超出 `[0, 1]` 时按边界处理，`NaN` 视为 0。
+    pub fn random(mut self, method: ApiMethod, probability: f64, faults: Vec<Fault>) -> Self {
+        self.random.push(RandomFaults {
+            method: Some(method),
+            probability: clamp_probability(probability),
+            faults,
+        });
+        self
+    }
+
+    /// 所有接口的每次请求以 `probability` 的概率注入 `faults` 中的一个，概率的处理同 [`FaultTransport::random`]
+    pub fn random_all(mut self, probability: f64, faults: Vec<Fault>) -> Self {
+        self.random.push(RandomFaults {
+            method: None,
+            probability: clamp_probability(probability),
+            faults,
+        });
+        self
+    }
+
+    /// 指定接口接下来的请求依次注入 `faults`，优先于随机故障
+    pub fn fail_next(&self, method: ApiMethod, faults: impl IntoIterator<Item = Fault>) {
+        self.scripted
+            .lock()
+            .unwrap()
+            .entry(method)
+            .or_default()
+            .extend(faults);
+    }
+
+    /// 已注入的故障，按发生顺序排列
+    pub fn injected(&self) -> Vec<(ApiMethod, Fault)> {
+        self.injected.lock().unwrap().clone()
+    }
+
+    /// 为本次请求选择故障
+    fn pick(&self, method: ApiMethod) -> Option<Fault> {
+        if let Some(fault) = self
+            .scripted
+            .lock()
+            .unwrap()
+            .get_mut(&method)
+            .and_then(VecDeque::pop_front)
+        {
+            return Some(fault);
+        }
+        let mut rng = self.rng.lock().unwrap();
+        self.random
+            .iter()
+            .filter(|rule| rule.method.is_none_or(|m| m == method) && !rule.faults.is_empty())
+            .find_map(|rule| {
+                (rng.next_f64() < rule.probability)
+                    .then(|| rule.faults[rng.next_u64() as usize % rule.faults.len()].clone())
+            })
+    }
+
+    async fn inject(
+        &self,
+        fault: Fault,
+        method: ApiMethod,
+        url: &str,
+        request: &ApiSignedRequest,
+        http_method: HttpMethod,
+        headers: &[(String, String)],
+    ) -> Result<TransportResponse, ElongError> {
+        match fault {
+            Fault::Timeout(after) => {
+                tokio::time::sleep(after).await;
+                Err(injected_io(io::ErrorKind::TimedOut, "request timed out"))
+            }
+            Fault::TimeoutAfterSend(after) => {
+                let _ = self
+                    .inner
+                    .send_with_headers(url, request, http_method, headers)
+                    .await;
+                tokio::time::sleep(after).await;
+                Err(injected_io(io::ErrorKind::TimedOut, "response timed out"))
+            }
+            Fault::ConnectionReset => Err(injected_io(
+                io::ErrorKind::ConnectionReset,
+                "connection reset by peer",
+            )),
+            Fault::TruncatedGzip => {
+                let response = self
+                    .inner
+                    .send_with_headers(url, request, http_method, headers)
+                    .await?;
+                let body = truncated_gzip(&response.body).await?;
+                Ok(TransportResponse { body, ..response })
+            }
+            Fault::HttpStatus(status) => Err(ElongError::HttpStatus(status)),
+            Fault::HtmlPage => Ok(TransportResponse::new(HTML_PAGE).with_status(200)),
+            Fault::ApiError(code) => {
+                let body = json!({"Code": code, "Result": null, "Guid": format!("fault-{}", method.name())});
+                Ok(TransportResponse::new(body.to_string()).with_status(200))
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Transport for FaultTransport {
+    async fn send(
+        &self,
+        url: &str,
+        request: &ApiSignedRequest,
+        http_method: HttpMethod,
+    ) -> Result<TransportResponse, ElongError> {
+        self.send_with_headers(url, request, http_method, &[]).await
+    }
+
+    async fn send_with_headers(
+        &self,
+        url: &str,
+        request: &ApiSignedRequest,
+        http_method: HttpMethod,
+        headers: &[(String, String)],
+    ) -> Result<TransportResponse, ElongError> {
+        // 经 SDK 发出的自定义接口名已被驻留，这里不会新增
+        let picked = ApiMethod::from_name(&request.method)
+            .ok()
+            .and_then(|method| Some((method, self.pick(method)?)));
+        match picked {
+            Some((method, fault)) => {
+                log::debug!("injecting {fault:?} into {}", method.name());
+                self.injected.lock().unwrap().push((method, fault.clone()));
+                self.inject(fault, method, url, request, http_method, headers)
+                    .await
+            }
+            None => {
+                self.inner
+                    .send_with_headers(url, request, http_method, headers)
+                    .await
+            }
+        }
+    }
+}
+
+fn clamp_probability(probability: f64) -> f64 {
+    if probability.is_nan() {
+        0.0
+    } else {
+        probability.clamp(0.0, 1.0)
+    }
+}
+
+fn injected_io(kind: io::ErrorKind, message: &str) -> ElongError {
+    ElongError::IoError(io::Error::new(kind, format!("{message} (injected)")))
+}
+
+/// 压缩后截断一半再解压，得到与真实连接中断相同的解压错误
+async fn truncated_gzip(body: &[u8]) -> Result<Vec<u8>, ElongError> {
+    let mut compressed = Vec::new();
+    GzipEncoder::new(body).read_to_end(&mut compressed).await?;
+    compressed.truncate(compressed.len() / 2);
+    let mut decompressed = Vec::new();
+    GzipDecoder::new(compressed.as_slice())
+        .read_to_end(&mut decompressed)
+        .await?;
+    Ok(decompressed)
+}
+
+/// SplitMix64，足够用于可复现的故障注入
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// `[0, 1)` 内的均匀分布
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
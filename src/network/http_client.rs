@@ -1,53 +1,218 @@
+use async_trait::async_trait;
 use reqwest::{
-    header::{HeaderMap, HeaderValue, ACCEPT_ENCODING, CONTENT_ENCODING},
-    Client,
+    header::{
+        HeaderMap, HeaderName, HeaderValue, ACCEPT_ENCODING, CONTENT_ENCODING, CONTENT_TYPE, DATE,
+    },
+    Client, Proxy, RequestBuilder,
 };
-use std::{sync::Arc, time::Duration};
+use std::{io, sync::Arc, time::Duration};
 
-use crate::elong::error::ElongError;
+use async_compression::tokio::bufread::GzipDecoder;
+use futures_util::TryStreamExt;
+use tokio::io::{AsyncRead, AsyncReadExt};
+use tokio_util::io::StreamReader;
+
+use crate::{elong::error::ElongError, request::api_request::ApiSignedRequest};
+
+use super::transport::{HttpMethod, Transport, TransportResponse};
+
+/// HTTP 客户端配置
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct HttpClientConfig {
+    /// 整体请求超时
+    pub timeout: Duration,
+    /// 建立连接超时
+    pub connect_timeout: Option<Duration>,
+    /// 读取超时
+    pub read_timeout: Option<Duration>,
+    /// 代理地址
+    pub proxy: Option<String>,
+    /// 每个主机的最大空闲连接数
+    pub pool_max_idle_per_host: usize,
+    /// 空闲连接的保活时间
+    pub pool_idle_timeout: Option<Duration>,
+    /// User-Agent
+    pub user_agent: Option<String>,
+    /// 默认请求头
+    pub default_headers: Vec<(String, String)>,
+    /// 响应体（解压后）的最大字节数
+    pub max_response_size: Option<usize>,
+}
+
+/// 默认响应体上限，`StaticList` 与 `IncrInv` 的大批量响应也远小于该值
+pub(crate) const DEFAULT_MAX_RESPONSE_SIZE: usize = 64 * 1024 * 1024;
+
+impl Default for HttpClientConfig {
+    fn default() -> Self {
+        HttpClientConfig {
+            timeout: Duration::from_secs(30),
+            connect_timeout: None,
+            read_timeout: None,
+            proxy: None,
+            pool_max_idle_per_host: 100,
+            pool_idle_timeout: None,
+            user_agent: None,
+            default_headers: Vec::new(),
+            max_response_size: Some(DEFAULT_MAX_RESPONSE_SIZE),
+        }
+    }
+}
 
 #[derive(Clone)]
 pub(crate) struct HttpClient {
     client: Arc<Client>,
+    max_response_size: Option<usize>,
 }
 
 impl HttpClient {
-    pub fn new() -> Self {
+    pub fn build(config: HttpClientConfig) -> Result<Self, ElongError> {
         let mut headers = HeaderMap::new();
         headers.insert(ACCEPT_ENCODING, HeaderValue::from_static("gzip"));
+        for (name, value) in &config.default_headers {
+            let name = HeaderName::from_bytes(name.as_bytes())
+                .map_err(|e| ElongError::ConfigError(format!("invalid header name {name}: {e}")))?;
+            let value = HeaderValue::from_str(value)
+                .map_err(|e| ElongError::ConfigError(format!("invalid header value: {e}")))?;
+            headers.insert(name, value);
+        }
+
+        let mut builder = Client::builder()
+            .pool_max_idle_per_host(config.pool_max_idle_per_host)
+            .timeout(config.timeout)
+            .default_headers(headers);
+        if let Some(connect_timeout) = config.connect_timeout {
+            builder = builder.connect_timeout(connect_timeout);
+        }
+        if let Some(read_timeout) = config.read_timeout {
+            builder = builder.read_timeout(read_timeout);
+        }
+        if let Some(pool_idle_timeout) = config.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(pool_idle_timeout);
+        }
+        if let Some(user_agent) = &config.user_agent {
+            builder = builder.user_agent(user_agent);
+        }
+        if let Some(proxy) = &config.proxy {
+            let proxy = Proxy::all(proxy)
+                .map_err(|e| ElongError::ConfigError(format!("invalid proxy {proxy}: {e}")))?;
+            builder = builder.proxy(proxy);
+        }
 
-        let client = Client::builder()
-            .pool_max_idle_per_host(100)
-            .timeout(Duration::from_secs(30))
-            .default_headers(headers)
+        let client = builder
             .build()
-            .expect("Failed to create HTTP client");
+            .map_err(|e| ElongError::ConfigError(format!("failed to create HTTP client: {e}")))?;
 
-        HttpClient {
+        Ok(HttpClient {
             client: Arc::new(client),
-        }
+            max_response_size: config.max_response_size,
+        })
     }
 
-    pub async fn get(&self, url: &str) -> Result<String, ElongError> {
-        let response = self.client.get(url).send().await?;
+    /// 发送请求并以流的方式读取响应
+    ///
+    /// gzip 响应边接收边解压，不会阻塞运行时；响应体超过上限时立即中止读取。
+    async fn execute(&self, request: RequestBuilder) -> Result<TransportResponse, ElongError> {
+        let response = request.send().await?;
         let status = response.status();
-        let headers = response.headers().clone();
-        let body = response.bytes().await?;
         if !status.is_success() {
-            return Err(ElongError::HttpError(format!(
-                "HTTP request failed with status: {status}"
-            )));
+            return Err(ElongError::HttpStatus(status.as_u16()));
         }
-        let body = if headers.get(CONTENT_ENCODING).is_some() {
+
+        let headers = response.headers();
+        let gzipped = headers
+            .get(CONTENT_ENCODING)
+            .is_some_and(|encoding| encoding.as_bytes().eq_ignore_ascii_case(b"gzip"));
+        let server_time = headers
+            .get(DATE)
+            .and_then(|date| date.to_str().ok())
+            .and_then(|date| chrono::DateTime::parse_from_rfc2822(date).ok())
+            .map(|date| date.with_timezone(&chrono::Utc));
+
+        let limit = self.max_response_size;
+        if let (Some(limit), Some(length)) = (limit, response.content_length()) {
+            if !gzipped && length > limit as u64 {
+                return Err(ElongError::ResponseTooLarge(limit));
+            }
+        }
+        let capacity = response
+            .content_length()
+            .unwrap_or(0)
+            .min(limit.unwrap_or(usize::MAX) as u64) as usize;
+
+        let stream = response.bytes_stream().map_err(io::Error::other);
+        let reader = StreamReader::new(stream);
+        let body = if gzipped {
             log::debug!("Response is gzipped");
-            let mut decoder = flate2::read::GzDecoder::new(body.as_ref());
-            let mut decompressed = Vec::new();
-            std::io::copy(&mut decoder, &mut decompressed)?;
-            String::from_utf8(decompressed)?
+            read_limited(GzipDecoder::new(reader), limit, capacity).await?
         } else {
             log::debug!("Response is not gzipped");
-            String::from_utf8(body.to_vec())?
+            read_limited(reader, limit, capacity).await?
+        };
+        Ok(TransportResponse {
+            body,
+            server_time,
+            status: Some(status.as_u16()),
+        })
+    }
+}
+
+/// 读取全部内容，超过 `limit` 字节时返回 [`ElongError::ResponseTooLarge`]
+async fn read_limited<R>(
+    reader: R,
+    limit: Option<usize>,
+    capacity: usize,
+) -> Result<Vec<u8>, ElongError>
+where
+    R: AsyncRead + Unpin,
+{
+    let mut body = Vec::with_capacity(capacity);
+    match limit {
+        Some(limit) => {
+            // 多读一个字节用于判断是否超限
+            reader.take(limit as u64 + 1).read_to_end(&mut body).await?;
+            if body.len() > limit {
+                return Err(ElongError::ResponseTooLarge(limit));
+            }
+        }
+        None => {
+            let mut reader = reader;
+            reader.read_to_end(&mut body).await?;
+        }
+    }
+    Ok(body)
+}
+
+#[async_trait]
+impl Transport for HttpClient {
+    async fn send(
+        &self,
+        url: &str,
+        request: &ApiSignedRequest,
+        http_method: HttpMethod,
+    ) -> Result<TransportResponse, ElongError> {
+        self.send_with_headers(url, request, http_method, &[]).await
+    }
+
+    async fn send_with_headers(
+        &self,
+        url: &str,
+        request: &ApiSignedRequest,
+        http_method: HttpMethod,
+        headers: &[(String, String)],
+    ) -> Result<TransportResponse, ElongError> {
+        // 完整地址包含签名与请求数据，只记录接口地址和接口名
+        log::debug!("{http_method:?} {url} method: {}", request.method);
+        let mut builder = match http_method {
+            HttpMethod::Get => self.client.get(format!("{url}?{}", request.to_params())),
+            HttpMethod::Post => self
+                .client
+                .post(url)
+                .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+                .body(request.to_params()),
         };
-        Ok(body)
+        for (name, value) in headers {
+            builder = builder.header(name.as_str(), value.as_str());
+        }
+        self.execute(builder).await
     }
 }
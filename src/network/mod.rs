@@ -1 +1,4 @@
+pub mod cassette;
+pub mod fault;
 pub(crate) mod http_client;
+pub mod transport;
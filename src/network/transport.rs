@@ -0,0 +1,90 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::{elong::error::ElongError, request::api_request::ApiSignedRequest};
+
+/// 传输层
+///
+/// 负责把已签名的请求发送到艺龙接口并返回原始响应体，
+/// 默认实现基于 reqwest，可替换为测试桩、录制或带埋点的实现。
+#[async_trait]
+pub trait Transport: Send + Sync {
+    /// 发送已签名请求，返回原始响应（已解压）
+    ///
+    /// `http_method` 为 [`HttpMethod::Post`] 时，参数应以表单形式放在请求体中。
+    async fn send(
+        &self,
+        url: &str,
+        request: &ApiSignedRequest,
+        http_method: HttpMethod,
+    ) -> Result<TransportResponse, ElongError>;
+
+    /// 附带额外请求头发送，由中间件添加的请求头经此传入
+    ///
+    /// 默认忽略请求头，直接调用 [`Transport::send`]。
+    async fn send_with_headers(
+        &self,
+        url: &str,
+        request: &ApiSignedRequest,
+        http_method: HttpMethod,
+        headers: &[(String, String)],
+    ) -> Result<TransportResponse, ElongError> {
+        let _ = headers;
+        self.send(url, request, http_method).await
+    }
+}
+
+/// 请求方式，两种方式签名相同
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum HttpMethod {
+    /// 参数放在查询字符串中
+    #[default]
+    Get,
+    /// 参数以 `application/x-www-form-urlencoded` 放在请求体中
+    Post,
+}
+
+/// 传输层返回的原始响应
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransportResponse {
+    /// 响应体（已解压），直接按字节解析，不再转换为 `String`
+    pub body: Vec<u8>,
+    /// 响应头 `Date` 表示的服务器时间，用于校准签名时间戳
+    pub server_time: Option<DateTime<Utc>>,
+    /// HTTP 状态码，非 HTTP 传输层为 `None`
+    pub status: Option<u16>,
+}
+
+impl TransportResponse {
+    pub fn new(body: impl Into<Vec<u8>>) -> Self {
+        TransportResponse {
+            body: body.into(),
+            server_time: None,
+            status: None,
+        }
+    }
+
+    /// 设置 HTTP 状态码
+    pub fn with_status(mut self, status: u16) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// 设置服务器时间
+    pub fn with_server_time(mut self, server_time: DateTime<Utc>) -> Self {
+        self.server_time = Some(server_time);
+        self
+    }
+}
+
+impl From<String> for TransportResponse {
+    fn from(body: String) -> Self {
+        TransportResponse::new(body)
+    }
+}
+
+impl From<Vec<u8>> for TransportResponse {
+    fn from(body: Vec<u8>) -> Self {
+        TransportResponse::new(body)
+    }
+}
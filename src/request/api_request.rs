@@ -1,6 +1,11 @@
-use urlencoding::encode;
+use std::collections::HashMap;
 
-use crate::elong::error::ElongError;
+use serde::Serialize;
+use serde_json::value::RawValue;
+use urlencoding::{decode, encode};
+use zeroize::Zeroizing;
+
+use crate::elong::{endpoints::ApiMethod, error::ElongError};
 
 pub struct ApiSignedRequest {
     /// 账户名
@@ -18,8 +23,8 @@ pub struct ApiSignedRequest {
 }
 
 pub struct ApiRequestPayload {
-    /// 版本号
-    pub version: f64,
+    /// 版本号，如 `"1.62"`
+    pub version: String,
     /// 语言
     pub local: String,
     /// 请求参数
@@ -30,33 +35,64 @@ impl ApiSignedRequest {
     /// 获取签名
     pub fn new(
         user: String,
-        app_key: String,
-        app_secret: String,
+        app_key: &str,
+        app_secret: &str,
+        method: String,
+        data: ApiRequestPayload,
+    ) -> Result<Self, ElongError> {
+        let timestamp = chrono::Local::now().timestamp();
+        Self::new_at(user, app_key, app_secret, method, data, timestamp)
+    }
+
+    /// 使用指定的 Unix 时间戳（秒）签名
+    pub fn new_at(
+        user: String,
+        app_key: &str,
+        app_secret: &str,
         method: String,
         data: ApiRequestPayload,
-    ) -> Self {
-        let data = data.to_json();
+        timestamp: i64,
+    ) -> Result<Self, ElongError> {
+        let data = data.to_json()?;
         let format = "json".to_string();
 
-        let timestamp = chrono::Local::now().timestamp().to_string();
+        let timestamp = timestamp.to_string();
         log::debug!("timestamp: {timestamp}");
-        let data_app_key = format!("{data}{app_key}");
-        log::debug!("data_app_key: {data_app_key}");
-        let data_app_key_sig = format!("{:x}", md5::compute(data_app_key));
-        log::debug!("data_app_key_signature: {data_app_key_sig}");
-        let sig_str = format!("{timestamp}{data_app_key_sig}{app_secret}");
-        log::debug!("sig_str: {sig_str}");
-        let signature = format!("{:x}", md5::compute(sig_str));
-        log::debug!("signature: {signature}");
-
-        ApiSignedRequest {
+        let signature = sign(&data, &timestamp, app_key, app_secret);
+
+        Ok(ApiSignedRequest {
             user,
             method,
             timestamp,
             format,
             data,
             signature,
+        })
+    }
+
+    /// 解析 [`ApiSignedRequest::to_params`] 生成的参数（查询字符串或表单），缺少参数时返回 `None`
+    pub fn from_params(params: &str) -> Option<Self> {
+        let mut values: HashMap<&str, String> = HashMap::new();
+        for pair in params.split('&') {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            let value = value.replace('+', " ");
+            let value = decode(&value).map(|v| v.into_owned()).unwrap_or(value);
+            values.insert(key, value);
         }
+        let mut take = |key: &str| values.remove(key);
+        Some(ApiSignedRequest {
+            user: take("user")?,
+            method: take("method")?,
+            timestamp: take("timestamp")?,
+            format: take("format")?,
+            data: take("data")?,
+            signature: take("signature")?,
+        })
+    }
+
+    /// 使用给定的 appKey 与 appSecret 校验签名
+    pub fn verify(&self, app_key: &str, app_secret: &str) -> bool {
+        sign(&self.data, &self.timestamp, app_key, app_secret) == self.signature
     }
 
     pub fn to_params(&self) -> String {
@@ -72,15 +108,54 @@ impl ApiSignedRequest {
     }
 }
 
+/// 艺龙签名算法：`md5(timestamp + md5(data + appKey) + appSecret)`
+///
+/// 签名原文包含 appKey 与 appSecret，任何中间值都不能写入日志，用完即清零。
+pub fn sign(data: &str, timestamp: &str, app_key: &str, app_secret: &str) -> String {
+    let data_app_key = Zeroizing::new(format!("{data}{app_key}"));
+    let data_app_key_sig = format!("{:x}", md5::compute(data_app_key.as_bytes()));
+    let sign_source = Zeroizing::new(format!("{timestamp}{data_app_key_sig}{app_secret}"));
+    format!("{:x}", md5::compute(sign_source.as_bytes()))
+}
+
+/// 签名数据的 JSON 结构，`Request` 为已序列化的请求参数
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct PayloadJson<'a> {
+    version: &'a str,
+    local: &'a str,
+    request: &'a RawValue,
+}
+
 impl ApiRequestPayload {
-    pub fn to_json(&self) -> String {
-        format!(
-            r#"{{"Version":"{}","Local":"{}","Request":{}}}"#,
-            self.version, self.local, self.request
-        )
+    /// 序列化为签名数据，`request` 不是合法的 JSON 时返回错误
+    pub fn to_json(&self) -> Result<String, ElongError> {
+        let request: &RawValue = serde_json::from_str(&self.request)?;
+        Ok(serde_json::to_string(&PayloadJson {
+            version: &self.version,
+            local: &self.local,
+            request,
+        })?)
     }
 }
 
 pub trait BaseRequest {
     fn to_json(&self) -> Result<String, ElongError>;
 }
+
+impl BaseRequest for serde_json::Value {
+    fn to_json(&self) -> Result<String, ElongError> {
+        Ok(serde_json::to_string(self)?)
+    }
+}
+
+/// 接口调用
+///
+/// 将请求类型与接口名、响应类型绑定，`ElongClient::call` 据此返回正确的响应类型。
+/// SDK 未内置的接口可以通过 [`ApiMethod::Custom`] 自行实现。
+pub trait ApiCall: BaseRequest {
+    /// 响应中 Result 的类型
+    type Response;
+    /// 接口
+    const METHOD: ApiMethod;
+}
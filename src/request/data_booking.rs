@@ -1,8 +1,11 @@
 use serde::{Deserialize, Serialize};
 
-use crate::elong::error::ElongError;
+use crate::{
+    elong::{endpoints::ApiMethod, error::ElongError},
+    response::data_booking::DataBookingResponse,
+};
 
-use super::api_request::BaseRequest;
+use super::api_request::{ApiCall, BaseRequest};
 
 #[derive(Debug, Serialize, Deserialize, Default)]
 #[serde(rename_all = "PascalCase")]
@@ -44,3 +47,8 @@ impl BaseRequest for DataBookingRequest {
         Ok(serde_json::to_string(self)?)
     }
 }
+
+impl ApiCall for DataBookingRequest {
+    type Response = DataBookingResponse;
+    const METHOD: ApiMethod = ApiMethod::DataBooking;
+}
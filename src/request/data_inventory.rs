@@ -1,7 +1,10 @@
-use crate::elong::error::ElongError;
+use crate::{
+    elong::{endpoints::ApiMethod, error::ElongError},
+    response::data_inventory::InventoryResponse,
+};
 use serde::{Deserialize, Serialize};
 
-use super::api_request::BaseRequest;
+use super::api_request::{ApiCall, BaseRequest};
 
 #[derive(Serialize, Deserialize, Debug, Default)]
 #[serde(rename_all = "PascalCase")]
@@ -25,3 +28,8 @@ impl BaseRequest for InventoryRequest {
         Ok(serde_json::to_string(self)?)
     }
 }
+
+impl ApiCall for InventoryRequest {
+    type Response = InventoryResponse;
+    const METHOD: ApiMethod = ApiMethod::DataInventory;
+}
@@ -1,8 +1,11 @@
 use serde::{Deserialize, Serialize};
 
-use crate::elong::error::ElongError;
+use crate::{
+    elong::{endpoints::ApiMethod, error::ElongError},
+    response::data_rate::DataRateResponse,
+};
 
-use super::api_request::BaseRequest;
+use super::api_request::{ApiCall, BaseRequest};
 
 #[derive(Debug, Serialize, Deserialize, Default)]
 #[serde(rename_all = "PascalCase")]
@@ -26,3 +29,8 @@ impl BaseRequest for DataRateRequest {
         Ok(serde_json::to_string(self)?)
     }
 }
+
+impl ApiCall for DataRateRequest {
+    type Response = DataRateResponse;
+    const METHOD: ApiMethod = ApiMethod::DataRate;
+}
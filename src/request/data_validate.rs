@@ -1,8 +1,11 @@
 use serde::{Deserialize, Serialize};
 
-use crate::elong::error::ElongError;
+use crate::{
+    elong::{endpoints::ApiMethod, error::ElongError},
+    response::data_validate::DataValidateResponse,
+};
 
-use super::api_request::BaseRequest;
+use super::api_request::{ApiCall, BaseRequest};
 
 #[derive(Debug, Serialize, Deserialize, Default)]
 #[serde(rename_all = "PascalCase")]
@@ -71,3 +74,8 @@ impl BaseRequest for DataValidateRequest {
         Ok(serde_json::to_string(self)?)
     }
 }
+
+impl ApiCall for DataValidateRequest {
+    type Response = DataValidateResponse;
+    const METHOD: ApiMethod = ApiMethod::DataValidate;
+}
@@ -1,6 +1,10 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{elong::error::ElongError, request::api_request::BaseRequest};
+use crate::{
+    elong::{endpoints::ApiMethod, error::ElongError},
+    request::api_request::{ApiCall, BaseRequest},
+    response::dictionary::DictionaryResponse,
+};
 
 #[derive(Debug, Serialize, Deserialize, Default)]
 #[serde(rename_all = "PascalCase")]
@@ -18,3 +22,8 @@ impl BaseRequest for DictionaryRequest {
         Ok(serde_json::to_string(self)?)
     }
 }
+
+impl ApiCall for DictionaryRequest {
+    type Response = DictionaryResponse;
+    const METHOD: ApiMethod = ApiMethod::Dictionary;
+}
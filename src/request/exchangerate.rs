@@ -1,6 +1,10 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{elong::error::ElongError, request::api_request::BaseRequest};
+use crate::{
+    elong::{endpoints::ApiMethod, error::ElongError},
+    request::api_request::{ApiCall, BaseRequest},
+    response::exchangerate::ExchangerateResponse,
+};
 
 #[derive(Debug, Serialize, Deserialize, Default)]
 #[serde(rename_all = "PascalCase")]
@@ -15,3 +19,8 @@ impl BaseRequest for ExchangerateRequest {
         Ok(serde_json::to_string(self)?)
     }
 }
+
+impl ApiCall for ExchangerateRequest {
+    type Response = ExchangerateResponse;
+    const METHOD: ApiMethod = ApiMethod::ExchangeRate;
+}
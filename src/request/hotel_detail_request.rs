@@ -1,6 +1,10 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{elong::error::ElongError, request::api_request::BaseRequest};
+use crate::{
+    elong::{endpoints::ApiMethod, error::ElongError},
+    request::api_request::{ApiCall, BaseRequest},
+    response::hotel_detail_response::HotelDetailResponse,
+};
 
 #[derive(Debug, Serialize, Deserialize, Default)]
 #[serde(rename_all = "PascalCase")]
@@ -50,3 +54,30 @@ impl BaseRequest for HotelDetailRequest {
         Ok(serde_json::to_string(self)?)
     }
 }
+
+impl ApiCall for HotelDetailRequest {
+    type Response = HotelDetailResponse;
+    const METHOD: ApiMethod = ApiMethod::HotelDetail;
+}
+
+/// 酒店爬虫详情，请求参数与 `HotelDetailRequest` 相同
+#[derive(Debug, Serialize, Deserialize, Default)]
+#[serde(transparent)]
+pub struct HotelCrawlDetailRequest(pub HotelDetailRequest);
+
+impl From<HotelDetailRequest> for HotelCrawlDetailRequest {
+    fn from(request: HotelDetailRequest) -> Self {
+        HotelCrawlDetailRequest(request)
+    }
+}
+
+impl BaseRequest for HotelCrawlDetailRequest {
+    fn to_json(&self) -> Result<String, ElongError> {
+        self.0.to_json()
+    }
+}
+
+impl ApiCall for HotelCrawlDetailRequest {
+    type Response = HotelDetailResponse;
+    const METHOD: ApiMethod = ApiMethod::HotelCrawlDetail;
+}
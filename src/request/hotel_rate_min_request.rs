@@ -1,6 +1,10 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{elong::error::ElongError, request::api_request::BaseRequest};
+use crate::{
+    elong::{endpoints::ApiMethod, error::ElongError},
+    request::api_request::{ApiCall, BaseRequest},
+    response::hotel_rate_min_response::HotelRateMinResponse,
+};
 
 /// 酒店最小价请求结构体
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -35,3 +39,8 @@ impl BaseRequest for HotelRateMinRequest {
         Ok(serde_json::to_string(self)?)
     }
 }
+
+impl ApiCall for HotelRateMinRequest {
+    type Response = HotelRateMinResponse;
+    const METHOD: ApiMethod = ApiMethod::HotelRateMin;
+}
@@ -1,8 +1,11 @@
 use serde::{Deserialize, Serialize};
 
-use crate::elong::error::ElongError;
+use crate::{
+    elong::{endpoints::ApiMethod, error::ElongError},
+    response::incr_id::IncrIdResponse,
+};
 
-use super::api_request::BaseRequest;
+use super::api_request::{ApiCall, BaseRequest};
 
 #[derive(Debug, Serialize, Deserialize, Default)]
 #[serde(rename_all = "PascalCase")]
@@ -25,3 +28,30 @@ impl BaseRequest for IncrIdRequest {
         Ok(serde_json::to_string(self)?)
     }
 }
+
+impl ApiCall for IncrIdRequest {
+    type Response = IncrIdResponse;
+    const METHOD: ApiMethod = ApiMethod::IncrId;
+}
+
+/// 增量编号分片，请求参数与 `IncrIdRequest` 相同
+#[derive(Debug, Serialize, Deserialize, Default)]
+#[serde(transparent)]
+pub struct IncrShardingIdRequest(pub IncrIdRequest);
+
+impl From<IncrIdRequest> for IncrShardingIdRequest {
+    fn from(request: IncrIdRequest) -> Self {
+        IncrShardingIdRequest(request)
+    }
+}
+
+impl BaseRequest for IncrShardingIdRequest {
+    fn to_json(&self) -> Result<String, ElongError> {
+        self.0.to_json()
+    }
+}
+
+impl ApiCall for IncrShardingIdRequest {
+    type Response = IncrIdResponse;
+    const METHOD: ApiMethod = ApiMethod::IncrShardingId;
+}
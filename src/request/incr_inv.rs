@@ -1,8 +1,11 @@
 use serde::{Deserialize, Serialize};
 
-use crate::elong::error::ElongError;
+use crate::{
+    elong::{endpoints::ApiMethod, error::ElongError},
+    response::incr_inv::IncrInvResponse,
+};
 
-use super::api_request::BaseRequest;
+use super::api_request::{ApiCall, BaseRequest};
 
 #[derive(Debug, Serialize, Deserialize, Default)]
 #[serde(rename_all = "PascalCase")]
@@ -20,3 +23,30 @@ impl BaseRequest for IncrInvRequest {
         Ok(serde_json::to_string(self)?)
     }
 }
+
+impl ApiCall for IncrInvRequest {
+    type Response = IncrInvResponse;
+    const METHOD: ApiMethod = ApiMethod::IncrInv;
+}
+
+/// 库存增量分片，请求参数与 `IncrInvRequest` 相同
+#[derive(Debug, Serialize, Deserialize, Default)]
+#[serde(transparent)]
+pub struct IncrShardingInvRequest(pub IncrInvRequest);
+
+impl From<IncrInvRequest> for IncrShardingInvRequest {
+    fn from(request: IncrInvRequest) -> Self {
+        IncrShardingInvRequest(request)
+    }
+}
+
+impl BaseRequest for IncrShardingInvRequest {
+    fn to_json(&self) -> Result<String, ElongError> {
+        self.0.to_json()
+    }
+}
+
+impl ApiCall for IncrShardingInvRequest {
+    type Response = IncrInvResponse;
+    const METHOD: ApiMethod = ApiMethod::IncrShardingInv;
+}
@@ -1,6 +1,10 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{elong::error::ElongError, request::api_request::BaseRequest};
+use crate::{
+    elong::{endpoints::ApiMethod, error::ElongError},
+    request::api_request::{ApiCall, BaseRequest},
+    response::incr_order::IncrOrderResponse,
+};
 
 #[derive(Debug, Serialize, Deserialize, Default)]
 #[serde(rename_all = "PascalCase")]
@@ -18,3 +22,8 @@ impl BaseRequest for IncrOrderRequest {
         Ok(serde_json::to_string(self)?)
     }
 }
+
+impl ApiCall for IncrOrderRequest {
+    type Response = IncrOrderResponse;
+    const METHOD: ApiMethod = ApiMethod::IncrOrder;
+}
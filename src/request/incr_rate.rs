@@ -1,8 +1,11 @@
 use serde::{Deserialize, Serialize};
 
-use crate::elong::error::ElongError;
+use crate::{
+    elong::{endpoints::ApiMethod, error::ElongError},
+    response::incr_rate::IncrRateResponse,
+};
 
-use super::api_request::BaseRequest;
+use super::api_request::{ApiCall, BaseRequest};
 
 #[derive(Debug, Serialize, Deserialize, Default)]
 #[serde(rename_all = "PascalCase")]
@@ -20,3 +23,30 @@ impl BaseRequest for IncrRateRequest {
         Ok(serde_json::to_string(self)?)
     }
 }
+
+impl ApiCall for IncrRateRequest {
+    type Response = IncrRateResponse;
+    const METHOD: ApiMethod = ApiMethod::IncrRate;
+}
+
+/// 价格增量分片，请求参数与 `IncrRateRequest` 相同
+#[derive(Debug, Serialize, Deserialize, Default)]
+#[serde(transparent)]
+pub struct IncrShardingRateRequest(pub IncrRateRequest);
+
+impl From<IncrRateRequest> for IncrShardingRateRequest {
+    fn from(request: IncrRateRequest) -> Self {
+        IncrShardingRateRequest(request)
+    }
+}
+
+impl BaseRequest for IncrShardingRateRequest {
+    fn to_json(&self) -> Result<String, ElongError> {
+        self.0.to_json()
+    }
+}
+
+impl ApiCall for IncrShardingRateRequest {
+    type Response = IncrRateResponse;
+    const METHOD: ApiMethod = ApiMethod::ShardingRate;
+}
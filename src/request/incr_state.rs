@@ -1,8 +1,11 @@
 use serde::{Deserialize, Serialize};
 
-use crate::elong::error::ElongError;
+use crate::{
+    elong::{endpoints::ApiMethod, error::ElongError},
+    response::incr_state::IncrStateResponse,
+};
 
-use super::api_request::BaseRequest;
+use super::api_request::{ApiCall, BaseRequest};
 
 #[derive(Debug, Serialize, Deserialize, Default)]
 #[serde(rename_all = "PascalCase")]
@@ -23,3 +26,30 @@ impl BaseRequest for IncrStateRequest {
         Ok(serde_json::to_string(self)?)
     }
 }
+
+impl ApiCall for IncrStateRequest {
+    type Response = IncrStateResponse;
+    const METHOD: ApiMethod = ApiMethod::IncrState;
+}
+
+/// 状态增量分片，请求参数与 `IncrStateRequest` 相同
+#[derive(Debug, Serialize, Deserialize, Default)]
+#[serde(transparent)]
+pub struct IncrShardingStateRequest(pub IncrStateRequest);
+
+impl From<IncrStateRequest> for IncrShardingStateRequest {
+    fn from(request: IncrStateRequest) -> Self {
+        IncrShardingStateRequest(request)
+    }
+}
+
+impl BaseRequest for IncrShardingStateRequest {
+    fn to_json(&self) -> Result<String, ElongError> {
+        self.0.to_json()
+    }
+}
+
+impl ApiCall for IncrShardingStateRequest {
+    type Response = IncrStateResponse;
+    const METHOD: ApiMethod = ApiMethod::IncrShardingState;
+}
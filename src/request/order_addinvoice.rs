@@ -1,6 +1,16 @@
+use std::fmt;
+
 use serde::{Deserialize, Serialize};
 
-use crate::{elong::error::ElongError, request::api_request::BaseRequest};
+use crate::{
+    elong::{
+        endpoints::ApiMethod,
+        error::ElongError,
+        redact::{mask, mask_opt},
+    },
+    request::api_request::{ApiCall, BaseRequest},
+    response::order_addinvoice::OrderAddinvoiceResponse,
+};
 
 #[derive(Debug, Serialize, Deserialize, Default)]
 #[serde(rename_all = "PascalCase")]
@@ -56,7 +66,7 @@ pub struct OrderAddinvoiceRequest {
     pub encrypt_option: Option<i32>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Default)]
+#[derive(Serialize, Deserialize, Default)]
 #[serde(rename_all = "PascalCase")]
 pub struct DedicatedInvoice {
     /// 纳税人识别号
@@ -75,7 +85,7 @@ pub struct DedicatedInvoice {
     pub register_phone_num: String,
 }
 
-#[derive(Debug, Serialize, Deserialize, Default)]
+#[derive(Serialize, Deserialize, Default)]
 #[serde(rename_all = "PascalCase")]
 pub struct DeliveryAddress {
     /// 省，纸质发票必填
@@ -109,3 +119,37 @@ impl BaseRequest for OrderAddinvoiceRequest {
         Ok(serde_json::to_string(self)?)
     }
 }
+
+impl ApiCall for OrderAddinvoiceRequest {
+    type Response = OrderAddinvoiceResponse;
+    const METHOD: ApiMethod = ApiMethod::OrderAddinvoice;
+}
+
+/// 收件信息包含个人信息，`Debug` 输出时脱敏
+impl fmt::Debug for DeliveryAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DeliveryAddress")
+            .field("province", &self.province)
+            .field("city", &self.city)
+            .field("district", &self.district)
+            .field("street", &mask_opt(&self.street))
+            .field("recipient_name", &mask_opt(&self.recipient_name))
+            .field("post_email", &mask_opt(&self.post_email))
+            .field("phone", &mask(&self.phone))
+            .field("email", &mask_opt(&self.email))
+            .finish()
+    }
+}
+
+/// 专票的注册信息包含银行账号与电话，`Debug` 输出时脱敏
+impl fmt::Debug for DedicatedInvoice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DedicatedInvoice")
+            .field("tax_payer_num", &self.tax_payer_num)
+            .field("tax_register_bank", &self.tax_register_bank)
+            .field("register_bank_num", &mask(&self.register_bank_num))
+            .field("shotel_address", &mask(&self.shotel_address))
+            .field("register_phone_num", &mask(&self.register_phone_num))
+            .finish()
+    }
+}
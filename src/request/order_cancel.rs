@@ -1,6 +1,10 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{elong::error::ElongError, request::api_request::BaseRequest};
+use crate::{
+    elong::{endpoints::ApiMethod, error::ElongError},
+    request::api_request::{ApiCall, BaseRequest},
+    response::order_cancel::OrderCancelResponse,
+};
 
 #[derive(Debug, Serialize, Deserialize, Default)]
 #[serde(rename_all = "PascalCase")]
@@ -27,3 +31,8 @@ impl BaseRequest for OrderCancelRequest {
         Ok(serde_json::to_string(self)?)
     }
 }
+
+impl ApiCall for OrderCancelRequest {
+    type Response = OrderCancelResponse;
+    const METHOD: ApiMethod = ApiMethod::OrderCancel;
+}
@@ -1,6 +1,16 @@
+use std::fmt;
+
 use serde::{Deserialize, Serialize};
 
-use crate::{elong::error::ElongError, request::api_request::BaseRequest};
+use crate::{
+    elong::{
+        endpoints::ApiMethod,
+        error::ElongError,
+        redact::{mask, mask_opt},
+    },
+    request::api_request::{ApiCall, BaseRequest},
+    response::order_create::OrderCreateResponse,
+};
 
 #[derive(Debug, Serialize, Deserialize, Default)]
 #[serde(rename_all = "PascalCase")]
@@ -139,7 +149,7 @@ pub struct OrderCreateRequest {
     pub day_price_list: Option<Vec<DayPrice>>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Default)]
+#[derive(Serialize, Deserialize, Default)]
 #[serde(rename_all = "PascalCase")]
 pub struct Invoice {
     /// 发票类型
@@ -192,7 +202,7 @@ pub struct Invoice {
     pub register_phone_num: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Default)]
+#[derive(Serialize, Deserialize, Default)]
 #[serde(rename_all = "PascalCase")]
 pub struct Recipient {
     /// 省份
@@ -222,7 +232,7 @@ pub struct Recipient {
     pub email: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Default)]
+#[derive(Serialize, Deserialize, Default)]
 #[serde(rename_all = "PascalCase")]
 pub struct Contact {
     /// 姓名
@@ -262,7 +272,7 @@ pub struct Contact {
     pub last_name: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Default)]
+#[derive(Serialize, Deserialize, Default)]
 #[serde(rename_all = "PascalCase")]
 pub struct CreditCard {
     /// 卡号（加密后的值）
@@ -299,7 +309,7 @@ pub struct CreditCard {
     pub mobile: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Default)]
+#[derive(Serialize, Deserialize, Default)]
 #[serde(rename_all = "PascalCase")]
 pub struct DoveCorpCard {
     /// 卡号（加密后的值）
@@ -340,7 +350,7 @@ pub struct OrderRoom {
     pub customers: Vec<Customer>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Default)]
+#[derive(Serialize, Deserialize, Default)]
 #[serde(rename_all = "PascalCase")]
 pub struct Customer {
     /// 姓名
@@ -445,3 +455,100 @@ impl BaseRequest for OrderCreateRequest {
         Ok(serde_json::to_string(self)?)
     }
 }
+
+impl ApiCall for OrderCreateRequest {
+    type Response = OrderCreateResponse;
+    const METHOD: ApiMethod = ApiMethod::OrderCreate;
+}
+
+// 以下结构包含个人信息，`Debug` 输出时脱敏
+
+impl fmt::Debug for Invoice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Invoice")
+            .field("invoice_type", &self.invoice_type)
+            .field("title_type", &self.title_type)
+            .field("title", &self.title)
+            .field("itin", &self.itin)
+            .field("item_name", &self.item_name)
+            .field("amount", &self.amount)
+            .field("recipient", &self.recipient)
+            .field("is_need_relation_order", &self.is_need_relation_order)
+            .field("tax_payer_num", &self.tax_payer_num)
+            .field("tax_register_bank", &self.tax_register_bank)
+            .field("register_bank_num", &mask_opt(&self.register_bank_num))
+            .field("register_address", &mask_opt(&self.register_address))
+            .field("register_phone_num", &mask_opt(&self.register_phone_num))
+            .finish()
+    }
+}
+
+impl fmt::Debug for Recipient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Recipient")
+            .field("province", &self.province)
+            .field("city", &self.city)
+            .field("district", &self.district)
+            .field("street", &mask(&self.street))
+            .field("postal_code", &self.postal_code)
+            .field("name", &mask(&self.name))
+            .field("phone", &mask(&self.phone))
+            .field("email", &mask_opt(&self.email))
+            .finish()
+    }
+}
+
+impl fmt::Debug for Contact {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Contact")
+            .field("name", &mask(&self.name))
+            .field("email", &mask_opt(&self.email))
+            .field("mobile_area_code", &self.mobile_area_code)
+            .field("mobile", &mask(&self.mobile))
+            .field("phone", &mask_opt(&self.phone))
+            .field("fax", &mask_opt(&self.fax))
+            .field("gender", &self.gender)
+            .field("first_name", &mask_opt(&self.first_name))
+            .field("last_name", &mask_opt(&self.last_name))
+            .finish()
+    }
+}
+
+impl fmt::Debug for CreditCard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CreditCard")
+            .field("number", &mask(&self.number))
+            .field("cvv", &"***")
+            .field("expiration_year", &"***")
+            .field("expiration_month", &"***")
+            .field("holder_name", &mask(&self.holder_name))
+            .field("id_type", &self.id_type)
+            .field("id_no", &mask(&self.id_no))
+            .field("mobile", &mask_opt(&self.mobile))
+            .finish()
+    }
+}
+
+impl fmt::Debug for DoveCorpCard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DoveCorpCard")
+            .field("number", &mask(&self.number))
+            .field("expiration_date", &"***")
+            .finish()
+    }
+}
+
+impl fmt::Debug for Customer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Customer")
+            .field("name", &mask(&self.name))
+            .field("gender", &self.gender)
+            .field("nationality", &self.nationality)
+            .field("nat", &self.nat)
+            .field("id_card_no", &mask_opt(&self.id_card_no))
+            .field("id_card_type", &self.id_card_type)
+            .field("first_name", &mask_opt(&self.first_name))
+            .field("last_name", &mask_opt(&self.last_name))
+            .finish()
+    }
+}
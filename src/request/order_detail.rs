@@ -1,6 +1,10 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{elong::error::ElongError, request::api_request::BaseRequest};
+use crate::{
+    elong::{endpoints::ApiMethod, error::ElongError},
+    request::api_request::{ApiCall, BaseRequest},
+    response::order_detail::OrderDetailReponse,
+};
 
 #[derive(Debug, Serialize, Deserialize, Default)]
 #[serde(rename_all = "PascalCase")]
@@ -23,3 +27,8 @@ impl BaseRequest for OrderDetailRequest {
         Ok(serde_json::to_string(self)?)
     }
 }
+
+impl ApiCall for OrderDetailRequest {
+    type Response = OrderDetailReponse;
+    const METHOD: ApiMethod = ApiMethod::OrderDetail;
+}
@@ -1,8 +1,14 @@
+use std::fmt;
+
 use serde::{Deserialize, Serialize};
 
-use crate::{elong::error::ElongError, request::api_request::BaseRequest};
+use crate::{
+    elong::{endpoints::ApiMethod, error::ElongError, redact::mask_opt},
+    request::api_request::{ApiCall, BaseRequest},
+    response::order_feedback::OrderFeedbackResponse,
+};
 
-#[derive(Debug, Serialize, Deserialize, Default)]
+#[derive(Serialize, Deserialize, Default)]
 #[serde(rename_all = "PascalCase")]
 pub struct OrderFeedbackRequest {
     /// 订单编号
@@ -30,3 +36,23 @@ impl BaseRequest for OrderFeedbackRequest {
         Ok(serde_json::to_string(self)?)
     }
 }
+
+impl ApiCall for OrderFeedbackRequest {
+    type Response = OrderFeedbackResponse;
+    const METHOD: ApiMethod = ApiMethod::OrderFeedback;
+}
+
+// 入住人姓名属于个人信息，`Debug` 输出时脱敏
+
+impl fmt::Debug for OrderFeedbackRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OrderFeedbackRequest")
+            .field("order_id", &self.order_id)
+            .field("arrival_date", &self.arrival_date)
+            .field("departure_date", &self.departure_date)
+            .field("customer_name", &mask_opt(&self.customer_name))
+            .field("room_number", &self.room_number)
+            .field("notes", &self.notes)
+            .finish()
+    }
+}
@@ -1,8 +1,14 @@
+use std::fmt;
+
 use serde::{Deserialize, Serialize};
 
-use crate::{elong::error::ElongError, request::api_request::BaseRequest};
+use crate::{
+    elong::{endpoints::ApiMethod, error::ElongError, redact::mask_opt},
+    request::api_request::{ApiCall, BaseRequest},
+    response::order_list::OrderListResponse,
+};
 
-#[derive(Debug, Serialize, Deserialize, Default)]
+#[derive(Serialize, Deserialize, Default)]
 #[serde(rename_all = "PascalCase")]
 pub struct OrderListRequest {
     /// 预定时间开始点，要求格式为 yyyy-MM-dd HH:mm:ss
@@ -59,3 +65,32 @@ impl BaseRequest for OrderListRequest {
         Ok(serde_json::to_string(self)?)
     }
 }
+
+impl ApiCall for OrderListRequest {
+    type Response = OrderListResponse;
+    const METHOD: ApiMethod = ApiMethod::OrderList;
+}
+
+// 手机与入住人姓名属于个人信息，`Debug` 输出时脱敏
+
+impl fmt::Debug for OrderListRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OrderListRequest")
+            .field("creation_time_from", &self.creation_time_from)
+            .field("creation_time_to", &self.creation_time_to)
+            .field("hotel_id", &self.hotel_id)
+            .field("room_type_id", &self.room_type_id)
+            .field("rate_plan_id", &self.rate_plan_id)
+            .field("arrival_date_from", &self.arrival_date_from)
+            .field("arrival_date_to", &self.arrival_date_to)
+            .field("departure_date_from", &self.departure_date_from)
+            .field("departure_date_to", &self.departure_date_to)
+            .field("min_update_time", &self.min_update_time)
+            .field("max_update_time", &self.max_update_time)
+            .field("mobile", &mask_opt(&self.mobile))
+            .field("customer_name", &mask_opt(&self.customer_name))
+            .field("status", &self.status)
+            .field("page_index", &self.page_index)
+            .finish()
+    }
+}
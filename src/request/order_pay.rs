@@ -1,11 +1,12 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    elong::error::ElongError,
+    elong::{endpoints::ApiMethod, error::ElongError},
     request::{
-        api_request::BaseRequest,
+        api_request::{ApiCall, BaseRequest},
         order_create::{CreditCard, DoveCorpCard},
     },
+    response::order_pay::OrderPayResponse,
 };
 
 #[derive(Debug, Serialize, Deserialize, Default)]
@@ -36,3 +37,8 @@ impl BaseRequest for OrderPayRequest {
         Ok(serde_json::to_string(self)?)
     }
 }
+
+impl ApiCall for OrderPayRequest {
+    type Response = OrderPayResponse;
+    const METHOD: ApiMethod = ApiMethod::OrderPay;
+}
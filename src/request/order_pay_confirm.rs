@@ -1,6 +1,10 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{elong::error::ElongError, request::api_request::BaseRequest};
+use crate::{
+    elong::{endpoints::ApiMethod, error::ElongError},
+    request::api_request::{ApiCall, BaseRequest},
+    response::order_pay_confirm::OrderPayConfirmResponse,
+};
 
 #[derive(Debug, Serialize, Deserialize, Default)]
 #[serde(rename_all = "PascalCase")]
@@ -21,3 +25,8 @@ impl BaseRequest for OrderPayConfirmRequest {
         Ok(serde_json::to_string(self)?)
     }
 }
+
+impl ApiCall for OrderPayConfirmRequest {
+    type Response = OrderPayConfirmResponse;
+    const METHOD: ApiMethod = ApiMethod::OrderPayConfirm;
+}
@@ -1,6 +1,10 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{elong::error::ElongError, request::api_request::BaseRequest};
+use crate::{
+    elong::{endpoints::ApiMethod, error::ElongError},
+    request::api_request::{ApiCall, BaseRequest},
+    response::order_promote::OrderPromoteResponse,
+};
 
 #[derive(Debug, Serialize, Deserialize, Default)]
 #[serde(rename_all = "PascalCase")]
@@ -14,3 +18,8 @@ impl BaseRequest for OrderPromoteRequest {
         Ok(serde_json::to_string(self)?)
     }
 }
+
+impl ApiCall for OrderPromoteRequest {
+    type Response = OrderPromoteResponse;
+    const METHOD: ApiMethod = ApiMethod::OrderPromote;
+}
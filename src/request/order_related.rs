@@ -1,6 +1,10 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{elong::error::ElongError, request::api_request::BaseRequest};
+use crate::{
+    elong::{endpoints::ApiMethod, error::ElongError},
+    request::api_request::{ApiCall, BaseRequest},
+    response::order_related::OrderRelatedResponse,
+};
 
 #[derive(Debug, Serialize, Deserialize, Default)]
 #[serde(rename_all = "PascalCase")]
@@ -20,3 +24,8 @@ impl BaseRequest for OrderRelatedRequest {
         Ok(serde_json::to_string(self)?)
     }
 }
+
+impl ApiCall for OrderRelatedRequest {
+    type Response = OrderRelatedResponse;
+    const METHOD: ApiMethod = ApiMethod::OrderRelated;
+}
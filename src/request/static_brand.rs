@@ -1,8 +1,11 @@
 use serde::{Deserialize, Serialize};
 
-use crate::elong::error::ElongError;
+use crate::{
+    elong::{endpoints::ApiMethod, error::ElongError},
+    response::static_brand::StaticBrandResponse,
+};
 
-use super::api_request::BaseRequest;
+use super::api_request::{ApiCall, BaseRequest};
 
 #[derive(Debug, Serialize, Deserialize, Default)]
 #[serde(rename_all = "PascalCase")]
@@ -20,3 +23,8 @@ impl BaseRequest for StaticBrandRequest {
         Ok(serde_json::to_string(self)?)
     }
 }
+
+impl ApiCall for StaticBrandRequest {
+    type Response = StaticBrandResponse;
+    const METHOD: ApiMethod = ApiMethod::StaticBrand;
+}
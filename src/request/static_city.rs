@@ -1,8 +1,11 @@
 use serde::{Deserialize, Serialize};
 
-use crate::elong::error::ElongError;
+use crate::{
+    elong::{endpoints::ApiMethod, error::ElongError},
+    response::static_city::StaticCityResponse,
+};
 
-use super::api_request::BaseRequest;
+use super::api_request::{ApiCall, BaseRequest};
 
 #[derive(Debug, Serialize, Deserialize, Default)]
 #[serde(rename_all = "PascalCase")]
@@ -24,3 +27,8 @@ impl BaseRequest for StaticCityRequest {
         Ok(serde_json::to_string(self)?)
     }
 }
+
+impl ApiCall for StaticCityRequest {
+    type Response = StaticCityResponse;
+    const METHOD: ApiMethod = ApiMethod::StaticCity;
+}
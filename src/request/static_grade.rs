@@ -1,8 +1,11 @@
 use serde::{Deserialize, Serialize};
 
-use crate::elong::error::ElongError;
+use crate::{
+    elong::{endpoints::ApiMethod, error::ElongError},
+    response::static_grade::StaticGradeResponse,
+};
 
-use super::api_request::BaseRequest;
+use super::api_request::{ApiCall, BaseRequest};
 
 #[derive(Debug, Serialize, Deserialize, Default)]
 #[serde(rename_all = "PascalCase")]
@@ -17,3 +20,8 @@ impl BaseRequest for StaticGradeRequest {
         Ok(serde_json::to_string(self)?)
     }
 }
+
+impl ApiCall for StaticGradeRequest {
+    type Response = StaticGradeResponse;
+    const METHOD: ApiMethod = ApiMethod::StaticGrade;
+}
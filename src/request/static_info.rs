@@ -1,7 +1,10 @@
-use crate::elong::error::ElongError;
+use crate::{
+    elong::{endpoints::ApiMethod, error::ElongError},
+    response::static_info::StaticInfoResponse,
+};
 use serde::{Deserialize, Serialize};
 
-use super::api_request::BaseRequest;
+use super::api_request::{ApiCall, BaseRequest};
 
 #[derive(Serialize, Deserialize, Debug, Default)]
 pub struct StaticInfoRequest {
@@ -19,3 +22,8 @@ impl BaseRequest for StaticInfoRequest {
         Ok(serde_json::to_string(self)?)
     }
 }
+
+impl ApiCall for StaticInfoRequest {
+    type Response = StaticInfoResponse;
+    const METHOD: ApiMethod = ApiMethod::StaticInfo;
+}
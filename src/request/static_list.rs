@@ -1,6 +1,9 @@
-use crate::elong::error::ElongError;
+use crate::{
+    elong::{endpoints::ApiMethod, error::ElongError},
+    response::static_list::StaticListResponse,
+};
 
-use super::api_request::BaseRequest;
+use super::api_request::{ApiCall, BaseRequest};
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug, Default)]
@@ -28,3 +31,8 @@ impl BaseRequest for StaticListRequest {
         Ok(serde_json::to_string(self)?)
     }
 }
+
+impl ApiCall for StaticListRequest {
+    type Response = StaticListResponse;
+    const METHOD: ApiMethod = ApiMethod::StaticList;
+}
@@ -1,9 +1,29 @@
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
-use crate::elong::error::ElongError;
+use crate::elong::{
+    endpoints::ApiMethod,
+    error::{ApiError, ElongError},
+    result_code::ElongResultCode,
+};
 
 pub trait BaseResponse: Sized {
     fn from_json(json: String) -> Result<Self, ElongError>;
+
+    /// 直接从响应字节解析，避免先转换为 `String`
+    fn from_slice(bytes: &[u8]) -> Result<Self, ElongError> {
+        let json = String::from_utf8(bytes.to_vec())?;
+        Self::from_json(json)
+    }
+}
+
+impl<T: DeserializeOwned> BaseResponse for ElongResponse<T> {
+    fn from_json(json: String) -> Result<Self, ElongError> {
+        Ok(serde_json::from_str(&json)?)
+    }
+
+    fn from_slice(bytes: &[u8]) -> Result<Self, ElongError> {
+        Ok(serde_json::from_slice(bytes)?)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -18,4 +38,81 @@ impl<T> ElongResponse<T> {
     pub fn is_success(&self) -> bool {
         self.code == "0"
     }
+
+    /// 返回码分类，可用于展示本地化的错误描述和处理建议
+    pub fn result_code(&self) -> ElongResultCode {
+        ElongResultCode::parse(&self.code)
+    }
+
+    /// 取出 Result
+    ///
+    /// `Code` 不为 `"0"` 时返回 [`ElongError::ApiError`]，成功但缺少 Result 时返回
+    /// [`ElongError::MissingResult`]。响应中不含接口名，错误的 `method` 为空，
+    /// 已知接口时使用 [`ElongResponse::into_result_for`]。
+    pub fn into_result(self) -> Result<T, ElongError> {
+        self.into_result_with("")
+    }
+
+    /// 同 [`ElongResponse::into_result`]，错误中带上接口名
+    pub fn into_result_for(self, method: ApiMethod) -> Result<T, ElongError> {
+        self.into_result_with(method.name())
+    }
+
+    fn into_result_with(self, method: &str) -> Result<T, ElongError> {
+        if !self.is_success() {
+            return Err(ApiError::new(&self.code, self.guid, method).into());
+        }
+        self.result.ok_or(ElongError::MissingResult(self.guid))
+    }
+}
+
+/// 直接从接口调用结果中取出 Result
+///
+/// ```ignore
+/// let cities = service.get_static_city(request).await.into_data()?;
+/// ```
+pub trait ElongResultExt<T> {
+    fn into_data(self) -> Result<T, ElongError>;
+}
+
+impl<T> ElongResultExt<T> for Result<ElongResponse<T>, ElongError> {
+    fn into_data(self) -> Result<T, ElongError> {
+        self?.into_result()
+    }
+}
+
+/// 按语言选择名称
+///
+/// `local` 以 `en` 开头且英文名称非空时返回英文，否则回退到中文名称。
+///
+/// ```
+/// use elong_sdk::response::api_response::localized;
+///
+/// assert_eq!(localized("en-US", "北京", Some("Beijing")), "Beijing");
+/// assert_eq!(localized("en-US", "北京", None), "北京");
+/// assert_eq!(localized("zh-CN", "北京", Some("Beijing")), "北京");
+/// ```
+pub fn localized<'a>(local: &str, zh: &'a str, en: Option<&'a str>) -> &'a str {
+    match en {
+        Some(en) if local.starts_with("en") && !en.is_empty() => en,
+        _ => zh,
+    }
+}
+
+/// 按语言选择可选名称
+///
+/// 规则同 [`localized`]，中文名称也可能缺失。
+///
+/// ```
+/// use elong_sdk::response::api_response::localized_opt;
+///
+/// assert_eq!(localized_opt("en-US", Some("朝阳区"), Some("Chaoyang")), Some("Chaoyang"));
+/// assert_eq!(localized_opt("en-US", Some("朝阳区"), Some("")), Some("朝阳区"));
+/// assert_eq!(localized_opt("zh-CN", None, Some("Chaoyang")), None);
+/// ```
+pub fn localized_opt<'a>(local: &str, zh: Option<&'a str>, en: Option<&'a str>) -> Option<&'a str> {
+    match en {
+        Some(en) if local.starts_with("en") && !en.is_empty() => Some(en),
+        _ => zh,
+    }
 }
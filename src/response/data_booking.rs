@@ -1,12 +1,7 @@
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
-use crate::elong::error::ElongError;
-
-use super::{
-    api_response::{BaseResponse, ElongResponse},
-    data_rp::RatePlan,
-};
+use super::data_rp::RatePlan;
 
 #[derive(Debug, Serialize, Deserialize, Default)]
 #[serde(rename_all = "PascalCase")]
@@ -268,10 +263,3 @@ pub struct GuaranteeResult {
     /// LadderParseList 取消规则明细 LadderParse[] N 参考LadderParse节点
     pub ladder_parse_list: Vec<LadderParse>,
 }
-
-impl BaseResponse for ElongResponse<DataBookingResponse> {
-    fn from_json(json: String) -> Result<Self, ElongError> {
-        log::debug!("ElongResponse<DataBookingResponse> json: {json}");
-        Ok(serde_json::from_str(&json)?)
-    }
-}
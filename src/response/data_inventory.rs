@@ -1,9 +1,5 @@
 use serde::{Deserialize, Serialize};
 
-use crate::elong::error::ElongError;
-
-use super::api_response::{BaseResponse, ElongResponse};
-
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct InventoryResponse {
@@ -46,10 +42,3 @@ pub struct Inventory {
     #[serde(rename = "IC_EndTime")]
     pub ic_end_time: Option<String>,
 }
-
-impl BaseResponse for ElongResponse<InventoryResponse> {
-    fn from_json(json: String) -> Result<Self, ElongError> {
-        log::debug!("ElongResponse<DataInventoryResponse> json: {json}");
-        Ok(serde_json::from_str(&json)?)
-    }
-}
@@ -1,10 +1,6 @@
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
-use crate::elong::error::ElongError;
-
-use super::api_response::{BaseResponse, ElongResponse};
-
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct DataRpResponse {
@@ -599,10 +595,3 @@ pub struct RelatedProduct {
     /// RatePlanId 价格计划ID Int N
     pub rate_plan_id: i32,
 }
-
-impl BaseResponse for ElongResponse<DataRpResponse> {
-    fn from_json(json: String) -> Result<Self, ElongError> {
-        log::debug!("ElongResponse<DataRpResponse> json: {json}");
-        Ok(serde_json::from_str(&json)?)
-    }
-}
@@ -1,10 +1,6 @@
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
-use crate::elong::error::ElongError;
-
-use super::api_response::{BaseResponse, ElongResponse};
-
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct DataValidateResponse {
@@ -209,10 +205,3 @@ pub struct Nat {
     /// List 国籍Code列表 String[] N 例如：["CN", "GB"]
     pub list: Vec<String>,
 }
-
-impl BaseResponse for ElongResponse<DataValidateResponse> {
-    fn from_json(json: String) -> Result<Self, ElongError> {
-        log::debug!("ElongResponse<DataValidateResponse> json: {json}");
-        Ok(serde_json::from_str(&json)?)
-    }
-}
@@ -1,9 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{
-    elong::error::ElongError,
-    response::api_response::{BaseResponse, ElongResponse},
-};
+use super::api_response::localized;
 
 #[derive(Debug, Serialize, Deserialize, Default)]
 #[serde(rename_all = "PascalCase")]
@@ -53,12 +50,28 @@ pub struct Theme {
     /// 主题中文名称
     pub name_cn: String,
     /// 主题英文名称
-    pub name_en: String,
+    pub name_en: Option<String>,
 }
 
-impl BaseResponse for ElongResponse<DictionaryResponse> {
-    fn from_json(json: String) -> Result<Self, ElongError> {
-        log::debug!("ElongResponse<DictionaryResponse> json: {json}");
-        Ok(serde_json::from_str(&json)?)
+impl Facility {
+    /// 按语言返回设施名称
+    pub fn name(&self, local: &str) -> &str {
+        localized(local, &self.facility_name, self.facility_name_en.as_deref())
+    }
+
+    /// 按语言返回设施分类名称
+    pub fn type_name(&self, local: &str) -> &str {
+        localized(
+            local,
+            &self.facility_type_name,
+            self.facility_type_name_en.as_deref(),
+        )
+    }
+}
+
+impl Theme {
+    /// 按语言返回主题名称
+    pub fn name(&self, local: &str) -> &str {
+        localized(local, &self.name_cn, self.name_en.as_deref())
     }
 }
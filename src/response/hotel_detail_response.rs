@@ -1,9 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{
-    elong::error::ElongError,
-    response::api_response::{BaseResponse, ElongResponse},
-};
+use super::api_response::localized;
 
 #[derive(Debug, Serialize, Deserialize, Default)]
 #[serde(rename_all = "PascalCase")]
@@ -17,13 +14,6 @@ pub struct HotelDetailResponse {
     pub exchange_rate_list: Option<Vec<ExchangeRate>>,
 }
 
-impl BaseResponse for ElongResponse<HotelDetailResponse> {
-    fn from_json(json: String) -> Result<Self, ElongError> {
-        log::debug!("ElongResponse<HotelDetailResponse> json: {json}");
-        Ok(serde_json::from_str(&json)?)
-    }
-}
-
 /// 酒店信息
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
@@ -1010,3 +1000,17 @@ pub struct AdditionalTaxItem {
     /// 另付税和服务费明细金额
     pub amount: Option<rust_decimal::Decimal>,
 }
+
+impl Room {
+    /// 按语言返回房型名称
+    pub fn name(&self, local: &str) -> &str {
+        localized(local, &self.name, self.name_en.as_deref())
+    }
+}
+
+impl Detail {
+    /// 按语言返回酒店名称
+    pub fn name(&self, local: &str) -> &str {
+        localized(local, &self.hotel_name, self.hotel_name_en.as_deref())
+    }
+}
@@ -1,10 +1,5 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{
-    elong::error::ElongError,
-    response::api_response::{BaseResponse, ElongResponse},
-};
-
 #[derive(Debug, Serialize, Deserialize, Default)]
 #[serde(rename_all = "PascalCase")]
 /// 酒店最低价响应
@@ -12,13 +7,6 @@ pub struct HotelRateMinResponse {
     pub hotels: Vec<Hotel>,
 }
 
-impl BaseResponse for ElongResponse<HotelRateMinResponse> {
-    fn from_json(json: String) -> Result<Self, ElongError> {
-        log::debug!("ElongResponse<HotelRateMinResponse> json: {json}");
-        Ok(serde_json::from_str(&json)?)
-    }
-}
-
 /// 酒店信息
 #[derive(Debug, Serialize, Deserialize, Default)]
 #[serde(rename_all = "PascalCase")]
@@ -1,9 +1,5 @@
 use serde::{Deserialize, Serialize};
 
-use crate::elong::error::ElongError;
-
-use super::api_response::{BaseResponse, ElongResponse};
-
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct IncrInvResponse {
@@ -50,10 +46,3 @@ pub struct Inventory {
     #[serde(rename = "IC_EndTime")]
     pub ic_end_time: Option<String>,
 }
-
-impl BaseResponse for ElongResponse<IncrInvResponse> {
-    fn from_json(json: String) -> Result<Self, ElongError> {
-        log::debug!("ElongResponse<IncrInvResponse> json: {json}");
-        Ok(serde_json::from_str(&json)?)
-    }
-}
@@ -1,10 +1,5 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{
-    elong::error::ElongError,
-    response::api_response::{BaseResponse, ElongResponse},
-};
-
 #[derive(Debug, Serialize, Deserialize, Default)]
 #[serde(rename_all = "PascalCase")]
 pub struct IncrOrderResponse {
@@ -59,10 +54,3 @@ pub struct Order {
     /// 此字段为 true 时，只要订单状态变成 V，就代表着订单被确认了。
     pub is_instant_confirm: Option<bool>,
 }
-
-impl BaseResponse for ElongResponse<IncrOrderResponse> {
-    fn from_json(json: String) -> Result<Self, ElongError> {
-        log::debug!("ElongResponse<IncrOrderResponse> json: {json}");
-        Ok(serde_json::from_str(&json)?)
-    }
-}
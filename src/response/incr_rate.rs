@@ -1,10 +1,6 @@
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
-use crate::elong::error::ElongError;
-
-use super::api_response::{BaseResponse, ElongResponse};
-
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct IncrRateResponse {
@@ -57,10 +53,3 @@ pub struct Rate {
     /// PriceLimitedType 限价类型 Int N 二进制bit位分别表示各个限价条件，0为非限价，详见
     pub price_limited_type: i32,
 }
-
-impl BaseResponse for ElongResponse<IncrRateResponse> {
-    fn from_json(json: String) -> Result<Self, ElongError> {
-        log::debug!("ElongResponse<IncrRateResponse> json: {json}");
-        Ok(serde_json::from_str(&json)?)
-    }
-}
@@ -1,9 +1,5 @@
 use serde::{Deserialize, Serialize};
 
-use crate::elong::error::ElongError;
-
-use super::api_response::{BaseResponse, ElongResponse};
-
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct IncrStateResponse {
@@ -39,10 +35,3 @@ pub struct State {
     /// RoomTypeId：销售房型 RatePlanId：产品 RatePlanPolicy：担保或预付规则
     pub state_type: String,
 }
-
-impl BaseResponse for ElongResponse<IncrStateResponse> {
-    fn from_json(json: String) -> Result<Self, ElongError> {
-        log::debug!("ElongResponse<IncrStateResponse> json: {json}");
-        Ok(serde_json::from_str(&json)?)
-    }
-}
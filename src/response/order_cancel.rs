@@ -1,10 +1,5 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{
-    elong::error::ElongError,
-    response::api_response::{BaseResponse, ElongResponse},
-};
-
 #[derive(Debug, Serialize, Deserialize, Default)]
 #[serde(rename_all = "PascalCase")]
 pub struct OrderCancelResponse {
@@ -17,10 +12,3 @@ pub struct OrderCancelResponse {
     /// 取消罚金值
     pub penalty_amount: Option<f64>,
 }
-
-impl BaseResponse for ElongResponse<OrderCancelResponse> {
-    fn from_json(json: String) -> Result<Self, ElongError> {
-        log::debug!("ElongResponse<OrderCancelResponse> json: {json}");
-        Ok(serde_json::from_str(&json)?)
-    }
-}
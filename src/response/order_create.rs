@@ -1,10 +1,5 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{
-    elong::error::ElongError,
-    response::api_response::{BaseResponse, ElongResponse},
-};
-
 #[derive(Debug, Serialize, Deserialize, Default)]
 #[serde(rename_all = "PascalCase")]
 pub struct OrderCreateResponse {
@@ -23,10 +18,3 @@ pub struct OrderCreateResponse {
     /// 支付错误信息
     pub payment_message: Option<String>,
 }
-
-impl BaseResponse for ElongResponse<OrderCreateResponse> {
-    fn from_json(json: String) -> Result<Self, ElongError> {
-        log::debug!("ElongResponse<OrderCreateResponse> json: {json}");
-        Ok(serde_json::from_str(&json)?)
-    }
-}
@@ -1,9 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{
-    elong::error::ElongError,
-    response::api_response::{BaseResponse, ElongResponse},
-};
+use super::api_response::localized;
 
 #[derive(Debug, Serialize, Deserialize, Default)]
 #[serde(rename_all = "PascalCase")]
@@ -905,7 +902,7 @@ pub struct OrderHotel {
     pub city_name: String,
 
     /// 酒店英文名（国际特有字段）
-    pub hotel_english_name: String,
+    pub hotel_english_name: Option<String>,
 
     /// 国家名称（国际特有字段）
     pub hotel_country_name: Option<String>,
@@ -1087,9 +1084,9 @@ pub struct Refund {
     pub refund_time: String,
 }
 
-impl BaseResponse for ElongResponse<OrderDetailReponse> {
-    fn from_json(json: String) -> Result<Self, ElongError> {
-        log::debug!("ElongResponse<OrderDetailReponse> json: {json}");
-        Ok(serde_json::from_str(&json)?)
+impl OrderHotel {
+    /// 按语言返回酒店名称
+    pub fn name(&self, local: &str) -> &str {
+        localized(local, &self.name, self.hotel_english_name.as_deref())
     }
 }
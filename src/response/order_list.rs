@@ -1,10 +1,5 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{
-    elong::error::ElongError,
-    response::api_response::{BaseResponse, ElongResponse},
-};
-
 #[derive(Debug, Serialize, Deserialize, Default)]
 #[serde(rename_all = "PascalCase")]
 pub struct OrderListResponse {
@@ -85,10 +80,3 @@ pub struct Order {
     /// 给艺龙备注
     pub note_to_elong: Option<String>,
 }
-
-impl BaseResponse for ElongResponse<OrderListResponse> {
-    fn from_json(json: String) -> Result<Self, ElongError> {
-        log::debug!("ElongResponse<OrderListResponse> json: {json}");
-        Ok(serde_json::from_str(&json)?)
-    }
-}
@@ -1,10 +1,5 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{
-    elong::error::ElongError,
-    response::api_response::{BaseResponse, ElongResponse},
-};
-
 #[derive(Debug, Serialize, Deserialize, Default)]
 #[serde(rename_all = "PascalCase")]
 pub struct OrderPayResponse {
@@ -15,10 +10,3 @@ pub struct OrderPayResponse {
     /// 备注（失败原因）
     pub notes: Option<String>,
 }
-
-impl BaseResponse for ElongResponse<OrderPayResponse> {
-    fn from_json(json: String) -> Result<Self, ElongError> {
-        log::debug!("ElongResponse<OrderPayResponse> json: {json}");
-        Ok(serde_json::from_str(&json)?)
-    }
-}
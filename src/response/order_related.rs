@@ -1,10 +1,5 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{
-    elong::error::ElongError,
-    response::api_response::{BaseResponse, ElongResponse},
-};
-
 #[derive(Debug, Serialize, Deserialize, Default)]
 #[serde(rename_all = "PascalCase")]
 pub struct OrderRelatedResponse {
@@ -22,10 +17,3 @@ pub struct Relation {
     /// 子订单
     pub child_id: i64,
 }
-
-impl BaseResponse for ElongResponse<OrderRelatedResponse> {
-    fn from_json(json: String) -> Result<Self, ElongError> {
-        log::debug!("ElongResponse<OrderRelatedResponse> json: {json}");
-        Ok(serde_json::from_str(&json)?)
-    }
-}
@@ -1,8 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::elong::error::ElongError;
-
-use super::api_response::{BaseResponse, ElongResponse};
+use super::api_response::{localized, localized_opt};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StaticBrandResponse {
@@ -40,9 +38,18 @@ pub struct Brand {
     pub opt_time: String,
 }
 
-impl BaseResponse for ElongResponse<StaticBrandResponse> {
-    fn from_json(json: String) -> Result<Self, ElongError> {
-        log::debug!("ElongResponse<StaticBrandResponse> json: {json}");
-        Ok(serde_json::from_str(&json)?)
+impl Brand {
+    /// 按语言返回品牌名称
+    pub fn name(&self, local: &str) -> &str {
+        localized(local, &self.brand_name, self.brand_name_en.as_deref())
+    }
+
+    /// 按语言返回品牌全称
+    pub fn full_name(&self, local: &str) -> Option<&str> {
+        localized_opt(
+            local,
+            self.brand_name_detail.as_deref(),
+            self.brand_name_detail_en.as_deref(),
+        )
     }
 }
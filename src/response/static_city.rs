@@ -1,8 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::elong::error::ElongError;
-
-use super::api_response::{BaseResponse, ElongResponse};
+use super::api_response::localized;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
@@ -16,17 +14,17 @@ pub struct StaticCityResponse {
 pub struct City {
     pub city_id: String,
     pub city_name: String,
-    pub city_name_en: String,
+    pub city_name_en: Option<String>,
     pub city_longitude: String,
     pub city_latitude: String,
     #[serde(rename = "CityParentID")]
     pub city_parent_id: String,
     pub province_id: String,
     pub province_name: String,
-    pub province_name_en: String,
+    pub province_name_en: Option<String>,
     pub country_id: String,
     pub country_name: String,
-    pub country_name_en: String,
+    pub country_name_en: Option<String>,
     pub country_code: String,
     ///Locations Location数据    Location[] Y   Location数据，包含行政区、商圈、标示物
     pub locations: Option<Vec<Location>>,
@@ -46,9 +44,26 @@ pub struct Location {
     pub location_type: i32,
 }
 
-impl BaseResponse for ElongResponse<StaticCityResponse> {
-    fn from_json(json: String) -> Result<Self, ElongError> {
-        log::debug!("ElongResponse<StaticCityRes> json: {json}");
-        Ok(serde_json::from_str(&json)?)
+impl City {
+    /// 按语言返回城市名称
+    pub fn name(&self, local: &str) -> &str {
+        localized(local, &self.city_name, self.city_name_en.as_deref())
+    }
+
+    /// 按语言返回省份名称
+    pub fn province_name(&self, local: &str) -> &str {
+        localized(local, &self.province_name, self.province_name_en.as_deref())
+    }
+
+    /// 按语言返回国家名称
+    pub fn country_name(&self, local: &str) -> &str {
+        localized(local, &self.country_name, self.country_name_en.as_deref())
+    }
+}
+
+impl Location {
+    /// 按语言返回 Location 名称
+    pub fn name(&self, local: &str) -> &str {
+        localized(local, &self.location_name, self.location_name_en.as_deref())
     }
 }
@@ -1,9 +1,5 @@
 use serde::{Deserialize, Serialize};
 
-use crate::elong::error::ElongError;
-
-use super::api_response::{BaseResponse, ElongResponse};
-
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct StaticGradeResponse {
@@ -63,9 +59,3 @@ pub struct CommentTag {
     /// HeatValue 标签热度 Int N
     pub heat_value: i32,
 }
-impl BaseResponse for ElongResponse<StaticGradeResponse> {
-    fn from_json(json: String) -> Result<Self, ElongError> {
-        log::debug!("ElongResponse<StaticGradeResponse> json: {json}");
-        Ok(serde_json::from_str(&json)?)
-    }
-}
@@ -1,8 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::elong::error::ElongError;
-
-use super::api_response::{BaseResponse, ElongResponse};
+use super::api_response::localized;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StaticGroupResponse {
@@ -32,9 +30,18 @@ pub struct Group {
     pub opt_time: String,
 }
 
-impl BaseResponse for ElongResponse<StaticGroupResponse> {
-    fn from_json(json: String) -> Result<Self, ElongError> {
-        log::debug!("ElongResponse<StaticGroupResponse> json: {json}");
-        Ok(serde_json::from_str(&json)?)
+impl Group {
+    /// 按语言返回集团简称
+    pub fn name(&self, local: &str) -> &str {
+        localized(local, &self.group_name, self.group_name_en.as_deref())
+    }
+
+    /// 按语言返回集团全称
+    pub fn full_name(&self, local: &str) -> &str {
+        localized(
+            local,
+            &self.group_name_detail,
+            self.group_name_detail_en.as_deref(),
+        )
     }
 }
@@ -1,8 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::elong::error::ElongError;
-
-use super::api_response::{BaseResponse, ElongResponse};
+use super::api_response::{localized, localized_opt};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
@@ -689,9 +687,150 @@ pub struct Location {
     pub url: Option<String>,
 }
 
-impl BaseResponse for ElongResponse<StaticInfoResponse> {
-    fn from_json(json: String) -> Result<Self, ElongError> {
-        log::debug!("ElongResponse<StaticHotelResponse> json: {json}");
-        Ok(serde_json::from_str(&json)?)
+impl Hotel {
+    /// 按语言返回酒店名称
+    pub fn name(&self, local: &str) -> &str {
+        localized(local, &self.hotel_name, self.hotel_name_en.as_deref())
+    }
+
+    /// 按语言返回酒店曾用名
+    pub fn hotel_used_name(&self, local: &str) -> Option<&str> {
+        localized_opt(
+            local,
+            self.hotel_used_name.as_deref(),
+            self.hotel_used_name_en.as_deref(),
+        )
+    }
+
+    /// 按语言返回酒店当地名称
+    pub fn hotel_name_local(&self, local: &str) -> Option<&str> {
+        localized_opt(
+            local,
+            self.hotel_name_local.as_deref(),
+            self.hotel_name_local_en.as_deref(),
+        )
+    }
+
+    /// 按语言返回酒店简称
+    pub fn short_name(&self, local: &str) -> Option<&str> {
+        localized_opt(
+            local,
+            self.short_name.as_deref(),
+            self.short_name_en.as_deref(),
+        )
+    }
+
+    /// 按语言返回集团名称
+    pub fn group_name(&self, local: &str) -> Option<&str> {
+        localized_opt(
+            local,
+            self.group_name.as_deref(),
+            self.group_name_en.as_deref(),
+        )
+    }
+
+    /// 按语言返回品牌名称
+    pub fn brand_name(&self, local: &str) -> Option<&str> {
+        localized_opt(
+            local,
+            self.brand_name.as_deref(),
+            self.brand_name_en.as_deref(),
+        )
+    }
+
+    /// 按语言返回国家名称
+    pub fn country_name(&self, local: &str) -> Option<&str> {
+        localized_opt(
+            local,
+            self.country_name.as_deref(),
+            self.country_name_en.as_deref(),
+        )
+    }
+
+    /// 按语言返回城市名称
+    pub fn city_name(&self, local: &str) -> Option<&str> {
+        localized_opt(
+            local,
+            self.city_name.as_deref(),
+            self.city_name_en.as_deref(),
+        )
+    }
+
+    /// 按语言返回行政区名称
+    pub fn district_name(&self, local: &str) -> Option<&str> {
+        localized_opt(
+            local,
+            self.district_name.as_deref(),
+            self.district_name_en.as_deref(),
+        )
+    }
+
+    /// 按语言返回商圈名称
+    pub fn business_zone_name(&self, local: &str) -> Option<&str> {
+        localized_opt(
+            local,
+            self.business_zone_name.as_deref(),
+            self.business_zone_name_en.as_deref(),
+        )
+    }
+
+    /// 按语言返回第二商圈名称
+    pub fn business_zone2_name(&self, local: &str) -> Option<&str> {
+        localized_opt(
+            local,
+            self.business_zone2_name.as_deref(),
+            self.business_zone2_name_en.as_deref(),
+        )
+    }
+}
+
+impl Room {
+    /// 按语言返回房型名称
+    pub fn name(&self, local: &str) -> &str {
+        localized(local, &self.room_name, self.room_name_en.as_deref())
+    }
+}
+
+impl Facility {
+    /// 按语言返回设施名称
+    pub fn name(&self, local: &str) -> Option<&str> {
+        localized_opt(
+            local,
+            self.facility_name.as_deref(),
+            self.facility_name_en.as_deref(),
+        )
+    }
+}
+
+impl Theme {
+    /// 按语言返回主题名称
+    pub fn name(&self, local: &str) -> Option<&str> {
+        localized_opt(
+            local,
+            self.theme_name.as_deref(),
+            self.theme_name_en.as_deref(),
+        )
+    }
+}
+
+impl HotelType {
+    /// 按语言返回酒店类型名称
+    pub fn name(&self, local: &str) -> Option<&str> {
+        localized_opt(
+            local,
+            self.hotel_type_name.as_deref(),
+            self.hotel_type_name_en.as_deref(),
+        )
+    }
+}
+
+impl Image {
+    /// 按语言返回图片类型名称
+    pub fn type_name(&self, local: &str) -> Option<&str> {
+        localized_opt(
+            local,
+            self.type_name.as_deref(),
+            self.type_name_en.as_deref(),
+        )
     }
 }
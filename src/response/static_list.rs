@@ -1,8 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::elong::error::ElongError;
-
-use super::api_response::{BaseResponse, ElongResponse};
+use super::api_response::localized;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
@@ -25,9 +23,9 @@ pub struct Hotel {
     pub update_time: String,
 }
 
-impl BaseResponse for ElongResponse<StaticListResponse> {
-    fn from_json(json: String) -> Result<Self, ElongError> {
-        log::debug!("ElongResponse<StaticListRes> json: {json}");
-        Ok(serde_json::from_str(&json)?)
+impl Hotel {
+    /// 按语言返回酒店名称
+    pub fn name(&self, local: &str) -> &str {
+        localized(local, &self.hotel_name, self.hotel_name_en.as_deref())
     }
 }
@@ -0,0 +1,155 @@
+//! 集成测试共用的传输层与服务构建
+#![allow(dead_code)]
+
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use elong_sdk::elong::{builder::ElongServiceBuilder, error::ElongError, service::ElongService};
+use elong_sdk::network::transport::{HttpMethod, Transport, TransportResponse};
+use elong_sdk::request::api_request::ApiSignedRequest;
+
+pub const TEST_USER: &str = "test_user";
+pub const TEST_KEY: &str = "test_key";
+pub const TEST_SECRET: &str = "test_secret";
+
+pub const INCR_ID_BODY: &str = r#"{"Code":"0","Result":{"LastId":1},"Guid":"g"}"#;
+
+/// 传输层收到的一次请求
+#[derive(Debug, Clone)]
+pub struct SentRequest {
+    pub url: String,
+    pub user: String,
+    pub method: String,
+    pub timestamp: String,
+    pub data: String,
+    pub signature: String,
+    pub http_method: HttpMethod,
+    pub headers: Vec<(String, String)>,
+}
+
+impl SentRequest {
+    /// 解析后的 `data`
+    pub fn payload(&self) -> serde_json::Value {
+        serde_json::from_str(&self.data).unwrap()
+    }
+}
+
+type Handler = dyn Fn(usize, &SentRequest) -> Result<TransportResponse, ElongError> + Send + Sync;
+
+/// 记录每次请求，由 `handler` 按调用序号（从 0 开始）与请求生成响应
+pub struct RecordingTransport {
+    handler: Box<Handler>,
+    requests: Mutex<Vec<SentRequest>>,
+}
+
+impl RecordingTransport {
+    pub fn new(
+        handler: impl Fn(usize, &SentRequest) -> Result<TransportResponse, ElongError>
+            + Send
+            + Sync
+            + 'static,
+    ) -> Arc<Self> {
+        Arc::new(RecordingTransport {
+            handler: Box::new(handler),
+            requests: Mutex::new(Vec::new()),
+        })
+    }
+
+    /// 每次返回同一响应，状态码为 200
+    pub fn body(body: &'static str) -> Arc<Self> {
+        Self::new(move |_, _| Ok(TransportResponse::new(body).with_status(200)))
+    }
+
+    /// 依次返回 `bodies`，用完后重复最后一个
+    pub fn scripted(bodies: Vec<&'static str>) -> Arc<Self> {
+        Self::new(move |call, _| {
+            Ok(TransportResponse::new(bodies[call.min(bodies.len() - 1)]).with_status(200))
+        })
+    }
+
+    /// 返回递增的 `LastId`，从 1 开始
+    pub fn counting() -> Arc<Self> {
+        Self::new(|call, _| {
+            Ok(TransportResponse::new(format!(
+                r#"{{"Code":"0","Result":{{"LastId":{}}},"Guid":"g"}}"#,
+                call + 1
+            ))
+            .with_status(200))
+        })
+    }
+
+    pub fn requests(&self) -> Vec<SentRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    pub fn calls(&self) -> usize {
+        self.requests.lock().unwrap().len()
+    }
+
+    pub fn last(&self) -> SentRequest {
+        self.requests.lock().unwrap().last().unwrap().clone()
+    }
+
+    pub fn methods(&self) -> Vec<String> {
+        self.requests().into_iter().map(|r| r.method).collect()
+    }
+
+    pub fn users(&self) -> Vec<String> {
+        self.requests().into_iter().map(|r| r.user).collect()
+    }
+
+    pub fn http_methods(&self) -> Vec<HttpMethod> {
+        self.requests().into_iter().map(|r| r.http_method).collect()
+    }
+}
+
+#[async_trait]
+impl Transport for RecordingTransport {
+    async fn send(
+        &self,
+        url: &str,
+        request: &ApiSignedRequest,
+        http_method: HttpMethod,
+    ) -> Result<TransportResponse, ElongError> {
+        self.send_with_headers(url, request, http_method, &[]).await
+    }
+
+    async fn send_with_headers(
+        &self,
+        url: &str,
+        request: &ApiSignedRequest,
+        http_method: HttpMethod,
+        headers: &[(String, String)],
+    ) -> Result<TransportResponse, ElongError> {
+        let sent = SentRequest {
+            url: url.to_string(),
+            user: request.user.clone(),
+            method: request.method.clone(),
+            timestamp: request.timestamp.clone(),
+            data: request.data.clone(),
+            signature: request.signature.clone(),
+            http_method,
+            headers: headers.to_vec(),
+        };
+        let call = {
+            let mut requests = self.requests.lock().unwrap();
+            requests.push(sent.clone());
+            requests.len() - 1
+        };
+        (self.handler)(call, &sent)
+    }
+}
+
+/// 使用测试账户的构建器
+pub fn account_builder() -> ElongServiceBuilder {
+    ElongService::builder().credentials(TEST_USER, TEST_KEY, TEST_SECRET)
+}
+
+/// 使用测试账户与指定传输层的构建器
+pub fn builder(transport: Arc<dyn Transport>) -> ElongServiceBuilder {
+    account_builder().transport(transport)
+}
+
+pub fn service(transport: Arc<dyn Transport>) -> ElongService {
+    builder(transport).build().unwrap()
+}
@@ -0,0 +1,92 @@
+mod common;
+
+use common::{service, RecordingTransport};
+use elong_sdk::elong::{endpoints::ApiMethod, error::ElongError};
+use elong_sdk::request::api_request::{ApiCall, BaseRequest};
+use elong_sdk::request::hotel_detail_request::HotelDetailRequest;
+use elong_sdk::request::incr_id::IncrIdRequest;
+use elong_sdk::request::incr_inv::IncrInvRequest;
+use elong_sdk::request::incr_rate::IncrRateRequest;
+use elong_sdk::request::incr_state::IncrStateRequest;
+use elong_sdk::Elong;
+use serde::{Deserialize, Serialize};
+
+/// 第三方自行扩展的接口
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct DestinationRequest {
+    query: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct DestinationResponse {
+    count: u32,
+}
+
+impl BaseRequest for DestinationRequest {
+    fn to_json(&self) -> Result<String, ElongError> {
+        Ok(serde_json::to_string(self)?)
+    }
+}
+
+impl ApiCall for DestinationRequest {
+    type Response = DestinationResponse;
+    const METHOD: ApiMethod = ApiMethod::Custom("hotel.destination");
+}
+
+#[tokio::test]
+async fn test_custom_api_call() {
+    let transport = RecordingTransport::body(r#"{"Code":"0","Result":{"Count":3},"Guid":"g"}"#);
+    let service = service(transport.clone());
+
+    let response = service
+        .call(DestinationRequest {
+            query: "北京".to_string(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(response.result.unwrap().count, 3);
+    assert_eq!(transport.methods(), vec!["hotel.destination"]);
+}
+
+#[tokio::test]
+async fn test_sharding_methods() {
+    let transport = RecordingTransport::body(r#"{"Code":"0","Result":null,"Guid":"g"}"#);
+    let service = service(transport.clone());
+
+    let _ = service.get_incr_inv(IncrInvRequest::default()).await;
+    let _ = service
+        .get_incr_sharding_inv(IncrInvRequest::default())
+        .await;
+    let _ = service.get_incr_rate(IncrRateRequest::default()).await;
+    let _ = service
+        .get_incr_sharding_rate(IncrRateRequest::default())
+        .await;
+    let _ = service.get_incr_id(IncrIdRequest::default()).await;
+    let _ = service.get_incr_sharding_id(IncrIdRequest::default()).await;
+    let _ = service.get_incr_state(IncrStateRequest::default()).await;
+    let _ = service
+        .get_incr_sharding_state(IncrStateRequest::default())
+        .await;
+    let _ = service.hotel_detail(HotelDetailRequest::default()).await;
+    let _ = service
+        .hotel_crawl_detail(HotelDetailRequest::default())
+        .await;
+
+    assert_eq!(
+        transport.methods(),
+        vec![
+            "hotel.incr.inv",
+            "hotel.incr.sharding.inv",
+            "hotel.incr.rate",
+            "hotel.incr.sharding.rate",
+            "hotel.incr.id",
+            "hotel.incr.sharding.id",
+            "hotel.incr.state",
+            "hotel.incr.sharding.state",
+            "hotel.detail",
+            "hotel.crawl.detail",
+        ]
+    );
+}
@@ -0,0 +1,62 @@
+#![cfg(feature = "blocking")]
+
+mod common;
+
+use std::sync::Arc;
+
+use common::{builder, RecordingTransport};
+use elong_sdk::elong::blocking::ElongBlockingService;
+use elong_sdk::request::incr_id::IncrIdRequest;
+use serde_json::json;
+
+fn service() -> (ElongBlockingService, Arc<RecordingTransport>) {
+    let transport = RecordingTransport::body(r#"{"Code":"0","Result":{"LastId":7},"Guid":"g"}"#);
+    let service = builder(transport.clone()).build_blocking().unwrap();
+    (service, transport)
+}
+
+#[test]
+fn test_blocking_call() {
+    let (service, transport) = service();
+
+    let response = service.get_incr_id(IncrIdRequest::default()).unwrap();
+    assert_eq!(response.result.unwrap().last_id, 7);
+
+    service
+        .call_raw("hotel.incr.inv", json!({"LastId": 1}))
+        .unwrap();
+    assert_eq!(transport.methods(), vec!["hotel.incr.id", "hotel.incr.inv"]);
+}
+
+#[test]
+fn test_blocking_from_threads() {
+    let (service, transport) = service();
+
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let service = service.clone();
+            std::thread::spawn(move || service.get_incr_id(IncrIdRequest::default()).unwrap())
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    assert_eq!(transport.calls(), 4);
+}
+
+#[test]
+fn test_blocking_local_and_version() {
+    let (service, transport) = service();
+
+    service
+        .with_local("en-US")
+        .with_version("2.0")
+        .get_incr_id(IncrIdRequest::default())
+        .unwrap();
+    let payload = transport.last().payload();
+    assert_eq!(payload["Local"], "en-US");
+    assert_eq!(payload["Version"], "2.0");
+
+    service.get_incr_id(IncrIdRequest::default()).unwrap();
+    assert_eq!(transport.last().payload()["Local"], "zh-CN");
+}
@@ -0,0 +1,59 @@
+mod common;
+
+use std::time::Duration;
+
+use common::{account_builder, builder, RecordingTransport, INCR_ID_BODY};
+use elong_sdk::elong::{endpoints::ApiEndpoint, error::ElongError};
+
+#[test]
+fn test_builder() {
+    let service = account_builder()
+        .base_url("http://127.0.0.1:8080/rest")
+        .timeout(Duration::from_secs(10))
+        .connect_timeout(Duration::from_secs(2))
+        .read_timeout(Duration::from_secs(5))
+        .pool_max_idle_per_host(8)
+        .pool_idle_timeout(Duration::from_secs(60))
+        .user_agent("elong-sdk-test")
+        .default_header("X-Trace-Id", "abc")
+        .proxy("http://127.0.0.1:3128")
+        .build();
+    assert!(service.is_ok());
+    assert_eq!(service.unwrap().url, "http://127.0.0.1:8080/rest");
+}
+
+#[test]
+fn test_builder_endpoint() {
+    let service = account_builder()
+        .endpoint(ApiEndpoint::Test)
+        .build()
+        .unwrap();
+    assert_eq!(service.url, ApiEndpoint::Test.url());
+}
+
+#[test]
+fn test_builder_invalid_proxy() {
+    let service = account_builder().proxy("not a proxy url").build();
+    assert!(service.is_err());
+}
+
+#[test]
+fn test_builder_invalid_header() {
+    let service = account_builder()
+        .default_header("bad header", "value")
+        .build();
+    assert!(service.is_err());
+}
+
+#[test]
+fn test_builder_http_settings_with_transport() {
+    let result = builder(RecordingTransport::body(INCR_ID_BODY))
+        .timeout(Duration::from_secs(5))
+        .build();
+    assert!(matches!(result, Err(ElongError::ConfigError(_))));
+
+    assert!(builder(RecordingTransport::body(INCR_ID_BODY))
+        .endpoint(ApiEndpoint::Test)
+        .build()
+        .is_ok());
+}
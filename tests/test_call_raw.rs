@@ -0,0 +1,53 @@
+mod common;
+
+use common::{service, RecordingTransport};
+use elong_sdk::elong::endpoints::ApiMethod;
+use serde_json::json;
+
+#[tokio::test]
+async fn test_call_raw() {
+    let transport =
+        RecordingTransport::body(r#"{"Code":"0","Result":{"Count":1,"Hotels":[]},"Guid":"g"}"#);
+    let service = service(transport.clone());
+
+    let response = service
+        .call_raw("hotel.list", json!({"CityId": "0101", "PageIndex": 1}))
+        .await
+        .unwrap();
+    assert_eq!(response.result.unwrap()["Count"], 1);
+
+    let request = transport.last();
+    assert_eq!(request.method, "hotel.list");
+    assert!(request
+        .data
+        .contains(r#""Request":{"CityId":"0101","PageIndex":1}"#));
+}
+
+#[tokio::test]
+async fn test_call_raw_api_error() {
+    let service = service(RecordingTransport::body(
+        r#"{"Code":"H000001|参数错误","Result":null,"Guid":"g"}"#,
+    ));
+
+    let err = service
+        .call_raw("hotel.sr.create", json!({}))
+        .await
+        .unwrap_err();
+    assert_eq!(err.api_error().unwrap().method, "hotel.sr.create");
+}
+
+#[test]
+fn test_from_name() {
+    assert_eq!(
+        ApiMethod::from_name("hotel.incr.inv").unwrap(),
+        ApiMethod::IncrInv
+    );
+    assert_eq!(
+        ApiMethod::from_name("hotel.sr.urge").unwrap(),
+        ApiMethod::Custom("hotel.sr.urge")
+    );
+    assert_eq!(ApiMethod::builtin("hotel.sr.urge"), None);
+    for method in ApiMethod::ALL {
+        assert_eq!(ApiMethod::builtin(method.name()), Some(*method));
+    }
+}
@@ -0,0 +1,217 @@
+mod common;
+
+use std::{fs, path::PathBuf, sync::Arc};
+
+use common::{builder, service, RecordingTransport, TEST_KEY, TEST_SECRET, TEST_USER};
+use elong_sdk::elong::error::ElongError;
+use elong_sdk::network::cassette::{CassetteTransport, Interaction};
+use elong_sdk::network::transport::TransportResponse;
+use elong_sdk::request::incr_id::IncrIdRequest;
+use elong_sdk::request::order_detail::OrderDetailRequest;
+use elong_sdk::response::api_response::ElongResultExt;
+use elong_sdk::response::order_detail::{
+    Contact, CreditCard, Customer, OrderDetailReponse, OrderRoom,
+};
+use elong_sdk::Elong;
+use serde_json::json;
+
+fn cassette_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("elong-cassette-{name}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+fn incr_id(last_time: &str) -> IncrIdRequest {
+    IncrIdRequest {
+        last_time: last_time.to_string(),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_record_then_replay() {
+    let dir = cassette_dir("replay");
+    let inner = RecordingTransport::counting();
+    let recorder = service(Arc::new(CassetteTransport::record(&dir, inner.clone())));
+    recorder.get_incr_id(incr_id("2024-01-01")).await.unwrap();
+    recorder.get_incr_id(incr_id("2024-01-02")).await.unwrap();
+
+    let replayer = service(Arc::new(CassetteTransport::replay(&dir)));
+    let second = replayer.get_incr_id(incr_id("2024-01-02")).await.unwrap();
+    let first = replayer.get_incr_id(incr_id("2024-01-01")).await.unwrap();
+    assert_eq!(first.result.unwrap().last_id, 1);
+    assert_eq!(second.result.unwrap().last_id, 2);
+    assert_eq!(inner.calls(), 2);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn test_recording_is_scrubbed() {
+    let dir = cassette_dir("scrubbed");
+    let transport = CassetteTransport::record(
+        &dir,
+        RecordingTransport::body(
+            r#"{"Code":"0","Result":{"OrderId":1,"Contact":{"Name":"李四光","Email":"guest@example.com"}},"Guid":"g"}"#,
+        ),
+    );
+    let path = transport.path_for("hotel.order.detail");
+    service(Arc::new(transport))
+        .call_raw(
+            "hotel.order.detail",
+            json!({"OrderId": 1, "Contact": {"Name": "张三丰", "Mobile": "13800138000"}}),
+        )
+        .await
+        .unwrap();
+
+    let content = fs::read_to_string(&path).unwrap();
+    for secret in [
+        TEST_USER,
+        TEST_KEY,
+        TEST_SECRET,
+        "13800138000",
+        "张三丰",
+        "李四光",
+        "guest@example.com",
+    ] {
+        assert!(!content.contains(secret), "{secret} leaked into cassette");
+    }
+    let interactions: Vec<Interaction> = serde_json::from_str(&content).unwrap();
+    assert_eq!(interactions.len(), 1);
+    assert_eq!(interactions[0].request.signature, "***");
+    assert_eq!(interactions[0].request.http_method, "GET");
+    assert_eq!(interactions[0].response.status, Some(200));
+    assert_eq!(interactions[0].request.data["Request"]["OrderId"], 1);
+    let body: serde_json::Value = serde_json::from_str(&interactions[0].response.body).unwrap();
+    assert_eq!(body["Result"]["OrderId"], 1);
+    assert_eq!(body["Result"]["Contact"]["Name"], "李***光");
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn test_rerecording_replaces_matching_interaction() {
+    let dir = cassette_dir("rerecord");
+    let transport = CassetteTransport::record(&dir, RecordingTransport::counting());
+    let path = transport.path_for("hotel.incr.id");
+    let recorder = service(Arc::new(transport));
+    recorder.get_incr_id(incr_id("2024-01-01")).await.unwrap();
+    recorder.get_incr_id(incr_id("2024-01-01")).await.unwrap();
+
+    let interactions: Vec<Interaction> = serde_json::from_slice(&fs::read(&path).unwrap()).unwrap();
+    assert_eq!(interactions.len(), 1);
+    assert!(interactions[0].response.body.contains(r#""LastId":2"#));
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn test_replay_miss_is_an_error() {
+    let dir = cassette_dir("miss");
+    let recorder = service(Arc::new(CassetteTransport::record(
+        &dir,
+        RecordingTransport::counting(),
+    )));
+    recorder.get_incr_id(incr_id("2024-01-01")).await.unwrap();
+
+    let replayer = service(Arc::new(CassetteTransport::replay(&dir)));
+    let err = replayer
+        .get_incr_id(incr_id("2024-02-01"))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ElongError::ConfigError(msg) if msg.contains("hotel.incr.id")));
+    let err = replayer
+        .get_incr_sharding_id(Default::default())
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ElongError::ConfigError(_)));
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn test_ignored_fields_do_not_affect_matching() {
+    let dir = cassette_dir("ignored");
+    let recorder = service(Arc::new(CassetteTransport::record(
+        &dir,
+        RecordingTransport::counting(),
+    )));
+    recorder.get_incr_id(incr_id("2024-01-01")).await.unwrap();
+
+    let replayer = service(Arc::new(
+        CassetteTransport::replay(&dir).ignore_field("LastTime"),
+    ));
+    let response = replayer.get_incr_id(incr_id("2030-06-01")).await.unwrap();
+    assert_eq!(response.result.unwrap().last_id, 1);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn test_builder_records_cassette() {
+    let dir = cassette_dir("builder");
+    let service = builder(RecordingTransport::counting())
+        .record_cassette(&dir)
+        .build()
+        .unwrap();
+    service.get_incr_id(incr_id("2024-01-01")).await.unwrap();
+
+    assert!(dir.join("hotel.incr.id.json").exists());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn test_scrubbed_order_detail_replays() {
+    let dir = cassette_dir("order-detail");
+    let detail = OrderDetailReponse {
+        order_id: 1,
+        contact: Contact {
+            name: "王小明".to_string(),
+            mobile: Some("13800138000".to_string()),
+            ..Default::default()
+        },
+        credit_card: Some(CreditCard {
+            expiration_year: Some(2030),
+            expiration_month: Some(12),
+            ..Default::default()
+        }),
+        order_rooms: vec![OrderRoom {
+            customers: vec![Customer {
+                name: "李四".to_string(),
+                id_type: Some(1),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    let body = json!({"Code": "0", "Result": detail, "Guid": "g"}).to_string();
+    let inner = RecordingTransport::new(move |_, _| {
+        Ok(TransportResponse::new(body.clone()).with_status(200))
+    });
+    let request = || OrderDetailRequest {
+        order_id: 1,
+        ..Default::default()
+    };
+
+    service(Arc::new(CassetteTransport::record(&dir, inner)))
+        .order_detail(request())
+        .await
+        .unwrap();
+    let replayed = service(Arc::new(CassetteTransport::replay(&dir)))
+        .order_detail(request())
+        .await
+        .into_data()
+        .unwrap();
+
+    assert_eq!(replayed.order_id, 1);
+    assert_ne!(replayed.contact.name, "王小明");
+    assert_eq!(replayed.contact.mobile.as_deref(), Some("1***0"));
+    let card = replayed.credit_card.unwrap();
+    assert_eq!(card.expiration_year, Some(0));
+    assert_eq!(card.expiration_month, Some(0));
+    assert_eq!(replayed.order_rooms[0].customers[0].id_type, Some(1));
+
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -1,3 +1,5 @@
+#![allow(deprecated)]
+
 use elong_sdk::elong::{endpoints::ApiEndpoint, service::ElongService};
 
 #[test]
@@ -0,0 +1,137 @@
+mod common;
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Utc};
+use common::{builder, RecordingTransport, INCR_ID_BODY};
+use elong_sdk::elong::{
+    clock::{Clock, FixedClock},
+    retry::RetryPolicy,
+    service::ElongService,
+};
+use elong_sdk::network::transport::TransportResponse;
+use elong_sdk::request::incr_id::IncrIdRequest;
+use elong_sdk::request::order_create::OrderCreateRequest;
+use elong_sdk::Elong;
+
+const NOW: i64 = 1_750_000_000;
+const ORDER_BODY: &str =
+    r#"{"Code":"0","Result":{"OrderId":1,"CancelTime":"2025-06-16 00:00:00"},"Guid":"g"}"#;
+const EXPIRED_BODY: &str = r#"{"Code":"H000002|时间戳过期","Guid":"g"}"#;
+
+/// 模拟服务器时间，时间戳相差超过 60 秒的请求返回时间戳过期
+fn server(server_time: Option<DateTime<Utc>>, body: &'static str) -> Arc<RecordingTransport> {
+    RecordingTransport::new(move |_, request| {
+        let timestamp: i64 = request.timestamp.parse().unwrap();
+        let server_now = server_time.map_or(timestamp, |t| t.timestamp());
+        let body = if (timestamp - server_now).abs() > 60 {
+            EXPIRED_BODY
+        } else {
+            body
+        };
+        let response = TransportResponse::new(body);
+        Ok(match server_time {
+            Some(server_time) => response.with_server_time(server_time),
+            None => response,
+        })
+    })
+}
+
+fn timestamps(transport: &RecordingTransport) -> Vec<i64> {
+    transport
+        .requests()
+        .iter()
+        .map(|request| request.timestamp.parse().unwrap())
+        .collect()
+}
+
+fn service(transport: Arc<RecordingTransport>, clock: Arc<dyn Clock>) -> ElongService {
+    builder(transport)
+        .clock(clock)
+        .retry_policy(RetryPolicy::none())
+        .build()
+        .unwrap()
+}
+
+fn at(secs: i64) -> DateTime<Utc> {
+    DateTime::from_timestamp(secs, 0).unwrap()
+}
+
+#[tokio::test]
+async fn test_fixed_clock_reproducible_signature() {
+    let transport = server(None, INCR_ID_BODY);
+    let service = service(transport.clone(), Arc::new(FixedClock::from_timestamp(NOW)));
+
+    service.get_incr_id(IncrIdRequest::default()).await.unwrap();
+    service.get_incr_id(IncrIdRequest::default()).await.unwrap();
+
+    let requests = transport.requests();
+    assert_eq!(requests[0].timestamp, NOW.to_string());
+    assert_eq!(requests[0].timestamp, requests[1].timestamp);
+    assert_eq!(requests[0].signature, requests[1].signature);
+}
+
+#[tokio::test]
+async fn test_skew_corrected_and_retried_once() {
+    let transport = server(Some(at(NOW + 300)), ORDER_BODY);
+    let clock = Arc::new(FixedClock::from_timestamp(NOW));
+    let service = service(transport.clone(), clock.clone());
+
+    // 下单接口默认不重试，签名被拒绝时仍会校准后重试一次
+    service
+        .order_create(OrderCreateRequest::default())
+        .await
+        .unwrap();
+    assert_eq!(timestamps(&transport), vec![NOW, NOW + 300]);
+    assert_eq!(service.client.clock_offset(), 300);
+
+    // 校准后的偏差用于后续签名
+    clock.advance(Duration::seconds(10));
+    service
+        .order_create(OrderCreateRequest::default())
+        .await
+        .unwrap();
+    assert_eq!(timestamps(&transport), vec![NOW, NOW + 300, NOW + 310]);
+}
+
+#[tokio::test]
+async fn test_skew_offset_shared_across_clones() {
+    let transport = server(
+        Some(at(NOW - 120)),
+        r#"{"Code":"0","Result":{"LastId":1},"Guid":"g"}"#,
+    );
+    let service = service(transport.clone(), Arc::new(FixedClock::from_timestamp(NOW)));
+    let cloned = service.clone();
+
+    service.get_incr_id(IncrIdRequest::default()).await.unwrap();
+    cloned.get_incr_id(IncrIdRequest::default()).await.unwrap();
+
+    assert_eq!(timestamps(&transport), vec![NOW, NOW - 120, NOW - 120]);
+}
+
+#[tokio::test]
+async fn test_no_retry_without_server_time() {
+    let transport = server(None, EXPIRED_BODY);
+    let service = service(transport.clone(), Arc::new(FixedClock::from_timestamp(NOW)));
+
+    // 服务器时间未知时无法校准，直接返回错误
+    let err = service
+        .get_incr_id(IncrIdRequest::default())
+        .await
+        .unwrap_err();
+    assert!(err.is_signature_error());
+    assert_eq!(timestamps(&transport).len(), 1);
+}
+
+#[tokio::test]
+async fn test_skew_retry_only_once() {
+    let transport = server(Some(at(NOW + 300)), EXPIRED_BODY);
+    let service = service(transport.clone(), Arc::new(FixedClock::from_timestamp(NOW)));
+
+    let err = service
+        .get_incr_id(IncrIdRequest::default())
+        .await
+        .unwrap_err();
+    assert!(err.is_signature_error());
+    assert_eq!(timestamps(&transport), vec![NOW, NOW + 300]);
+}
@@ -0,0 +1,314 @@
+mod common;
+
+use std::{
+    fs,
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime},
+};
+
+use common::{RecordingTransport, INCR_ID_BODY};
+use elong_sdk::elong::{
+    credentials::{
+        CredentialProvider, Credentials, EnvCredentials, FileCredentials, StaticCredentials,
+    },
+    endpoints::ApiMethod,
+    error::ElongError,
+    rate_limit::{Quota, RateLimitConfig},
+    secret::Secret,
+    service::ElongService,
+};
+use elong_sdk::request::api_request::{ApiRequestPayload, ApiSignedRequest};
+use elong_sdk::request::incr_id::IncrIdRequest;
+use elong_sdk::Elong;
+
+fn service(provider: Arc<dyn CredentialProvider>) -> (ElongService, Arc<RecordingTransport>) {
+    let transport = RecordingTransport::body(INCR_ID_BODY);
+    let service = ElongService::builder()
+        .credentials_provider(provider)
+        .transport(transport.clone())
+        .build()
+        .unwrap();
+    (service, transport)
+}
+
+fn accounts() -> StaticCredentials {
+    StaticCredentials::new()
+        .default_account(Credentials::new("user_default", "key", "secret"))
+        .account("channel_a", Credentials::new("user_a", "key_a", "secret_a"))
+        .account("channel_b", Credentials::new("user_b", "key_b", "secret_b"))
+}
+
+#[tokio::test]
+async fn test_per_call_account() {
+    let (service, transport) = service(Arc::new(accounts()));
+
+    service.get_incr_id(IncrIdRequest::default()).await.unwrap();
+    service
+        .with_account("channel_a")
+        .get_incr_id(IncrIdRequest::default())
+        .await
+        .unwrap();
+    service
+        .with_account("channel_b")
+        .get_incr_id(IncrIdRequest::default())
+        .await
+        .unwrap();
+
+    assert_eq!(transport.users(), vec!["user_default", "user_a", "user_b"]);
+}
+
+#[tokio::test]
+async fn test_unknown_account() {
+    let (service, transport) = service(Arc::new(accounts()));
+
+    let err = service
+        .with_account("missing")
+        .get_incr_id(IncrIdRequest::default())
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ElongError::ConfigError(_)));
+    assert!(transport.users().is_empty());
+}
+
+/// 可以随时替换的账户信息
+struct Rotating(Mutex<Arc<Credentials>>);
+
+impl CredentialProvider for Rotating {
+    fn credentials(&self, _account: Option<&str>) -> Result<Arc<Credentials>, ElongError> {
+        Ok(self.0.lock().unwrap().clone())
+    }
+}
+
+#[tokio::test]
+async fn test_secret_rotation_without_rebuild() {
+    let provider = Arc::new(Rotating(Mutex::new(Arc::new(Credentials::new(
+        "user", "key", "old",
+    )))));
+    let (service, transport) = service(provider.clone());
+
+    service.get_incr_id(IncrIdRequest::default()).await.unwrap();
+    *provider.0.lock().unwrap() = Arc::new(Credentials::new("user", "key", "new"));
+    service.get_incr_id(IncrIdRequest::default()).await.unwrap();
+
+    let requests = transport.requests();
+    assert_ne!(requests[0].signature, requests[1].signature);
+}
+
+/// 修改凭据文件，并确保修改时间变化，不依赖文件系统的时间精度
+fn rewrite(path: &std::path::Path, content: &str, offset: u64) {
+    fs::write(path, content).unwrap();
+    fs::File::options()
+        .write(true)
+        .open(path)
+        .unwrap()
+        .set_modified(SystemTime::now() + Duration::from_secs(offset))
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_file_credentials_reload() {
+    let path = std::env::temp_dir().join(format!("elong-credentials-{}.json", std::process::id()));
+    fs::write(
+        &path,
+        r#"{"default":{"username":"file_user","app_key":"k","app_secret":"s"}}"#,
+    )
+    .unwrap();
+    let provider = Arc::new(FileCredentials::new(&path).unwrap());
+    let (service, transport) = service(provider.clone());
+
+    service.get_incr_id(IncrIdRequest::default()).await.unwrap();
+
+    rewrite(
+        &path,
+        r#"{"default":{"username":"rotated_user","app_key":"k","app_secret":"s2"},
+            "accounts":{"channel_a":{"username":"file_a","app_key":"k","app_secret":"s"}}}"#,
+        10,
+    );
+    // 请求路径不访问文件，显式重新加载后才生效
+    service.get_incr_id(IncrIdRequest::default()).await.unwrap();
+    assert!(provider.reload().unwrap());
+    assert!(!provider.reload().unwrap());
+    service.get_incr_id(IncrIdRequest::default()).await.unwrap();
+    service
+        .with_account("channel_a")
+        .get_incr_id(IncrIdRequest::default())
+        .await
+        .unwrap();
+
+    // 文件损坏时继续使用上一次的内容
+    rewrite(&path, "not json", 20);
+    assert!(provider.reload().is_err());
+    service.get_incr_id(IncrIdRequest::default()).await.unwrap();
+
+    fs::remove_file(&path).unwrap();
+    assert_eq!(
+        transport.users(),
+        vec![
+            "file_user",
+            "file_user",
+            "rotated_user",
+            "file_a",
+            "rotated_user"
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_file_credentials_watch() {
+    let path = std::env::temp_dir().join(format!(
+        "elong-credentials-watch-{}.json",
+        std::process::id()
+    ));
+    fs::write(
+        &path,
+        r#"{"default":{"username":"file_user","app_key":"k","app_secret":"s"}}"#,
+    )
+    .unwrap();
+    let provider = Arc::new(
+        FileCredentials::new(&path)
+            .unwrap()
+            .reload_interval(Duration::from_millis(10)),
+    );
+    let watcher = provider.watch();
+
+    rewrite(
+        &path,
+        r#"{"default":{"username":"watched_user","app_key":"k","app_secret":"s"}}"#,
+        10,
+    );
+    let mut username = String::new();
+    for _ in 0..200 {
+        username = provider.credentials(None).unwrap().username.clone();
+        if username == "watched_user" {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    assert_eq!(username, "watched_user");
+
+    drop(provider);
+    tokio::time::timeout(Duration::from_secs(1), watcher)
+        .await
+        .unwrap()
+        .unwrap();
+    fs::remove_file(&path).unwrap();
+}
+
+#[tokio::test]
+async fn test_env_credentials() {
+    std::env::set_var("ELONG_TENANT19_USERNAME", "env_user");
+    std::env::set_var("ELONG_TENANT19_APP_KEY", "env_key");
+    std::env::set_var("ELONG_TENANT19_APP_SECRET", "env_secret");
+    let provider = Arc::new(EnvCredentials::new());
+    let (service, transport) = service(provider.clone());
+
+    service
+        .with_account("tenant19")
+        .get_incr_id(IncrIdRequest::default())
+        .await
+        .unwrap();
+
+    // 环境变量只读取一次，显式重新加载后才生效
+    std::env::set_var("ELONG_TENANT19_USERNAME", "rotated_env_user");
+    let first = provider.credentials(Some("tenant19")).unwrap();
+    assert!(Arc::ptr_eq(
+        &first,
+        &provider.credentials(Some("tenant19")).unwrap()
+    ));
+    provider.reload();
+    service
+        .with_account("tenant19")
+        .get_incr_id(IncrIdRequest::default())
+        .await
+        .unwrap();
+    assert_eq!(transport.users(), vec!["env_user", "rotated_env_user"]);
+
+    let err = service
+        .with_account("tenant19_missing")
+        .get_incr_id(IncrIdRequest::default())
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("ELONG_TENANT19_MISSING_USERNAME"));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_rate_limit_per_account() {
+    let transport = RecordingTransport::body(INCR_ID_BODY);
+    let service = ElongService::builder()
+        .credentials_provider(Arc::new(accounts()))
+        .transport(transport.clone())
+        .rate_limit(
+            RateLimitConfig::new().method(ApiMethod::IncrId, Quota::per_second(1.0).burst(1)),
+        )
+        .build()
+        .unwrap();
+
+    let started = tokio::time::Instant::now();
+    service
+        .with_account("channel_a")
+        .get_incr_id(IncrIdRequest::default())
+        .await
+        .unwrap();
+    service
+        .with_account("channel_b")
+        .get_incr_id(IncrIdRequest::default())
+        .await
+        .unwrap();
+    // 不同账户各自的配额，不需要等待
+    assert_eq!(started.elapsed(), Duration::ZERO);
+
+    service
+        .with_account("channel_a")
+        .get_incr_id(IncrIdRequest::default())
+        .await
+        .unwrap();
+    assert!(started.elapsed() >= Duration::from_millis(900));
+}
+
+#[test]
+fn test_credentials_debug_hides_secret() {
+    let debug = format!(
+        "{:?}",
+        Credentials::new("user", "app_key_value", "super_secret")
+    );
+    assert!(!debug.contains("super_secret"));
+    assert!(!debug.contains("app_key_value"));
+    assert!(debug.contains("user"));
+}
+
+#[test]
+fn test_secret_debug_and_expose() {
+    let secret = Secret::new("super_secret");
+    assert_eq!(format!("{secret:?}"), "Secret(***)");
+    assert_eq!(secret.expose(), "super_secret");
+
+    let credentials: Credentials =
+        serde_json::from_str(r#"{"username":"u","app_key":"k","app_secret":"s"}"#).unwrap();
+    assert_eq!(credentials.app_secret.expose(), "s");
+
+    assert_eq!(Secret::new("s"), credentials.app_secret);
+    assert_ne!(Secret::new("s2"), credentials.app_secret);
+    assert_ne!(Secret::new(""), credentials.app_secret);
+}
+
+#[test]
+fn test_signature_borrows_secrets() {
+    let credentials = Credentials::new("user", "key", "secret");
+    let signed = ApiSignedRequest::new_at(
+        credentials.username.clone(),
+        credentials.app_key.expose(),
+        credentials.app_secret.expose(),
+        "hotel.incr.id".to_string(),
+        ApiRequestPayload {
+            version: "1.62".to_string(),
+            local: "zh-CN".to_string(),
+            request: "{}".to_string(),
+        },
+        1_700_000_000,
+    )
+    .unwrap();
+    let data = r#"{"Version":"1.62","Local":"zh-CN","Request":{}}"#;
+    let inner = format!("{:x}", md5::compute(format!("{data}key")));
+    let expected = format!("{:x}", md5::compute(format!("1700000000{inner}secret")));
+    assert!(signed.to_params().contains(&expected));
+}
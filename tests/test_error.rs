@@ -0,0 +1,75 @@
+mod common;
+
+use std::sync::Arc;
+
+use common::{builder, RecordingTransport};
+use elong_sdk::elong::{
+    error::{ApiError, ElongError},
+    retry::RetryPolicy,
+    service::ElongService,
+};
+use elong_sdk::request::incr_id::IncrIdRequest;
+use elong_sdk::request::order_create::OrderCreateRequest;
+use elong_sdk::Elong;
+
+fn service(bodies: Vec<&'static str>) -> (ElongService, Arc<RecordingTransport>) {
+    let transport = RecordingTransport::scripted(bodies);
+    let service = builder(transport.clone())
+        .retry_policy(RetryPolicy::default().jitter(false).backoff(
+            std::time::Duration::from_millis(1),
+            std::time::Duration::from_millis(1),
+        ))
+        .build()
+        .unwrap();
+    (service, transport)
+}
+
+#[tokio::test]
+async fn test_api_error_from_code() {
+    let (service, _) = service(vec![
+        r#"{"Code":"H001022|房量不足，不可订","Result":null,"Guid":"guid-1"}"#,
+    ]);
+
+    let err = service
+        .order_create(OrderCreateRequest::default())
+        .await
+        .unwrap_err();
+    let api_error = err.api_error().unwrap();
+    assert_eq!(api_error.code, "H001022");
+    assert_eq!(api_error.message, "房量不足，不可订");
+    assert_eq!(api_error.guid.as_deref(), Some("guid-1"));
+    assert_eq!(api_error.method, "hotel.order.create");
+    assert!(err.is_inventory_unavailable());
+    assert!(!err.is_retryable());
+}
+
+#[tokio::test]
+async fn test_busy_code_retried() {
+    let (service, transport) = service(vec![
+        r#"{"Code":"H000997|系统繁忙，请稍后再试","Result":null,"Guid":"guid-1"}"#,
+        r#"{"Code":"0","Result":{"LastId":7},"Guid":"guid-2"}"#,
+    ]);
+
+    let result = service.get_incr_id(IncrIdRequest::default()).await.unwrap();
+    assert_eq!(result.result.unwrap().last_id, 7);
+    assert_eq!(transport.calls(), 2);
+}
+
+#[test]
+fn test_error_classification() {
+    let err: ElongError = ApiError::new("H000004|签名错误", None, "hotel.incr.id").into();
+    assert!(err.is_signature_error());
+    assert!(!err.is_auth_error());
+
+    let err: ElongError = ApiError::new("H000003|用户名不存在", None, "hotel.incr.id").into();
+    assert!(err.is_auth_error());
+
+    assert!(ElongError::HttpStatus(503).is_retryable());
+    assert!(!ElongError::HttpStatus(404).is_retryable());
+    assert!(ElongError::HttpStatus(401).is_auth_error());
+
+    let err: ElongError = serde_json::from_str::<serde_json::Value>("{")
+        .unwrap_err()
+        .into();
+    assert!(std::error::Error::source(&err).is_some());
+}
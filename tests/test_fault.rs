@@ -0,0 +1,187 @@
+mod common;
+
+use std::{io, sync::Arc, time::Duration};
+
+use common::{builder, RecordingTransport};
+use elong_sdk::elong::{
+    endpoints::ApiMethod, error::ElongError, retry::RetryPolicy, service::ElongService,
+};
+use elong_sdk::network::fault::{Fault, FaultTransport};
+use elong_sdk::network::transport::Transport;
+use elong_sdk::request::incr_id::IncrIdRequest;
+use elong_sdk::Elong;
+
+fn service(transport: Arc<dyn Transport>, retry: RetryPolicy) -> ElongService {
+    builder(transport).retry_policy(retry).build().unwrap()
+}
+
+fn fast_retry() -> RetryPolicy {
+    RetryPolicy::default()
+        .max_attempts(5)
+        .backoff(Duration::from_millis(1), Duration::from_millis(1))
+}
+
+async fn incr_id(service: &ElongService) -> Result<i64, ElongError> {
+    let response = service.get_incr_id(IncrIdRequest::default()).await?;
+    Ok(response.into_result()?.last_id)
+}
+
+fn io_kind(err: &ElongError) -> io::ErrorKind {
+    match err {
+        ElongError::IoError(err) => err.kind(),
+        other => panic!("unexpected error {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_scripted_faults_are_retried() {
+    let inner = RecordingTransport::counting();
+    let faults = Arc::new(FaultTransport::new(inner.clone(), 1));
+    faults.fail_next(
+        ApiMethod::IncrId,
+        [
+            Fault::Timeout(Duration::from_millis(1)),
+            Fault::ConnectionReset,
+            Fault::HttpStatus(502),
+            Fault::ApiError("H000997|系统繁忙".to_string()),
+        ],
+    );
+    let service = service(faults.clone(), fast_retry());
+
+    assert_eq!(incr_id(&service).await.unwrap(), 1);
+    assert_eq!(inner.calls(), 1);
+    assert_eq!(faults.injected().len(), 4);
+    assert!(faults
+        .injected()
+        .iter()
+        .all(|(method, _)| *method == ApiMethod::IncrId));
+}
+
+#[tokio::test]
+async fn test_each_fault_surfaces_as_production_error() {
+    let inner = RecordingTransport::counting();
+    let faults = Arc::new(FaultTransport::new(inner.clone(), 1));
+    let service = service(faults.clone(), RetryPolicy::none());
+
+    faults.fail_next(ApiMethod::IncrId, [Fault::Timeout(Duration::ZERO)]);
+    let err = incr_id(&service).await.unwrap_err();
+    assert_eq!(io_kind(&err), io::ErrorKind::TimedOut);
+    assert!(err.is_retryable());
+
+    faults.fail_next(ApiMethod::IncrId, [Fault::ConnectionReset]);
+    let err = incr_id(&service).await.unwrap_err();
+    assert_eq!(io_kind(&err), io::ErrorKind::ConnectionReset);
+
+    faults.fail_next(ApiMethod::IncrId, [Fault::TruncatedGzip]);
+    let err = incr_id(&service).await.unwrap_err();
+    assert_eq!(io_kind(&err), io::ErrorKind::UnexpectedEof);
+    assert!(err.is_retryable());
+
+    faults.fail_next(ApiMethod::IncrId, [Fault::HttpStatus(502)]);
+    let err = incr_id(&service).await.unwrap_err();
+    assert!(matches!(err, ElongError::HttpStatus(502)));
+
+    faults.fail_next(ApiMethod::IncrId, [Fault::HtmlPage]);
+    let err = incr_id(&service).await.unwrap_err();
+    assert!(matches!(err, ElongError::SerdeError(_)));
+    assert!(!err.is_retryable());
+
+    faults.fail_next(
+        ApiMethod::IncrId,
+        [Fault::ApiError("H001022|房量不足".into())],
+    );
+    let err = incr_id(&service).await.unwrap_err();
+    assert_eq!(err.api_error().unwrap().code, "H001022");
+
+    // 截断的响应已到达下游，其余故障均未到达
+    assert_eq!(inner.calls(), 1);
+    assert_eq!(incr_id(&service).await.unwrap(), 2);
+}
+
+#[tokio::test]
+async fn test_timeout_after_send_reaches_inner() {
+    let inner = RecordingTransport::counting();
+    let faults = Arc::new(FaultTransport::new(inner.clone(), 1));
+    faults.fail_next(ApiMethod::IncrId, [Fault::TimeoutAfterSend(Duration::ZERO)]);
+    let service = service(faults, RetryPolicy::none());
+
+    let err = incr_id(&service).await.unwrap_err();
+    assert_eq!(io_kind(&err), io::ErrorKind::TimedOut);
+    assert_eq!(inner.calls(), 1);
+    assert_eq!(incr_id(&service).await.unwrap(), 2);
+}
+
+#[tokio::test]
+async fn test_random_faults_are_reproducible() {
+    async fn run(seed: u64) -> (Vec<(ApiMethod, Fault)>, Vec<bool>) {
+        let faults = Arc::new(
+            FaultTransport::new(RecordingTransport::counting(), seed).random(
+                ApiMethod::IncrId,
+                0.5,
+                vec![Fault::ConnectionReset, Fault::HttpStatus(502)],
+            ),
+        );
+        let service = service(faults.clone(), RetryPolicy::none());
+        let mut outcomes = Vec::new();
+        for _ in 0..32 {
+            outcomes.push(incr_id(&service).await.is_ok());
+        }
+        (faults.injected(), outcomes)
+    }
+
+    let (injected, outcomes) = run(7).await;
+    assert_eq!(run(7).await, (injected.clone(), outcomes.clone()));
+    assert!(!injected.is_empty() && injected.len() < 32);
+    assert_eq!(outcomes.iter().filter(|ok| !**ok).count(), injected.len());
+    assert!(injected.contains(&(ApiMethod::IncrId, Fault::ConnectionReset)));
+    assert!(injected.contains(&(ApiMethod::IncrId, Fault::HttpStatus(502))));
+    assert_ne!(run(8).await.0, injected);
+}
+
+#[tokio::test]
+async fn test_random_faults_only_hit_selected_method() {
+    let faults = Arc::new(
+        FaultTransport::new(RecordingTransport::counting(), 3).random(
+            ApiMethod::OrderCreate,
+            1.0,
+            vec![Fault::HttpStatus(502)],
+        ),
+    );
+    let service = service(faults.clone(), RetryPolicy::none());
+    for expected in 1..=3 {
+        assert_eq!(incr_id(&service).await.unwrap(), expected);
+    }
+    assert!(faults.injected().is_empty());
+}
+
+#[tokio::test]
+async fn test_wrap_transport() {
+    let inner = RecordingTransport::counting();
+    let service = builder(inner.clone())
+        .retry_policy(RetryPolicy::none())
+        .wrap_transport(|transport| {
+            Arc::new(FaultTransport::new(transport, 0).random_all(1.0, vec![Fault::HtmlPage]))
+        })
+        .build()
+        .unwrap();
+
+    let err = incr_id(&service).await.unwrap_err();
+    assert!(matches!(err, ElongError::SerdeError(_)));
+    assert_eq!(inner.calls(), 0);
+}
+
+#[tokio::test]
+async fn test_random_probability_clamped() {
+    let faults = Arc::new(
+        FaultTransport::new(RecordingTransport::counting(), 5)
+            .random(ApiMethod::IncrId, f64::NAN, vec![Fault::ConnectionReset])
+            .random(ApiMethod::IncrId, -1.0, vec![Fault::ConnectionReset])
+            .random_all(2.0, vec![Fault::HttpStatus(502)]),
+    );
+    let service = service(faults.clone(), RetryPolicy::none());
+    for _ in 0..8 {
+        let err = incr_id(&service).await.unwrap_err();
+        assert!(matches!(err, ElongError::HttpStatus(502)));
+    }
+    assert_eq!(faults.injected().len(), 8);
+}
@@ -0,0 +1,179 @@
+mod common;
+
+use std::sync::{Arc, Mutex};
+
+use async_compression::tokio::bufread::GzipEncoder;
+use common::account_builder;
+use elong_sdk::elong::{
+    endpoints::ApiMethod,
+    error::ElongError,
+    middleware::{Middleware, MiddlewareRequest, Next},
+    retry::RetryPolicy,
+    service::ElongService,
+};
+use elong_sdk::network::transport::TransportResponse;
+use elong_sdk::request::incr_id::IncrIdRequest;
+use elong_sdk::Elong;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+};
+
+const INCR_ID_BODY: &str = r#"{"Code":"0","Result":{"LastId":42},"Guid":"g"}"#;
+
+/// 只处理一个连接的 HTTP 服务，返回地址与收到的原始请求
+async fn serve(body: Vec<u8>, gzip: bool) -> (String, Arc<Mutex<String>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let received = Arc::new(Mutex::new(String::new()));
+    let captured = received.clone();
+    tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let mut request = Vec::new();
+        let mut buf = [0u8; 4096];
+        loop {
+            let n = stream.read(&mut buf).await.unwrap();
+            request.extend_from_slice(&buf[..n]);
+            let text = String::from_utf8_lossy(&request);
+            if let Some(end) = text.find("\r\n\r\n") {
+                let length = text
+                    .lines()
+                    .find_map(|line| line.strip_prefix("content-length: "))
+                    .map_or(0, |len| len.trim().parse::<usize>().unwrap());
+                if request.len() >= end + 4 + length {
+                    break;
+                }
+            }
+            if n == 0 {
+                break;
+            }
+        }
+        *captured.lock().unwrap() = String::from_utf8_lossy(&request).into_owned();
+
+        let mut head = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n",
+            body.len()
+        );
+        if gzip {
+            head.push_str("Content-Encoding: gzip\r\n");
+        }
+        head.push_str("\r\n");
+        stream.write_all(head.as_bytes()).await.unwrap();
+        stream.write_all(&body).await.unwrap();
+        stream.shutdown().await.unwrap();
+    });
+    (format!("http://{addr}/rest"), received)
+}
+
+async fn gzip(body: &[u8]) -> Vec<u8> {
+    let mut encoded = Vec::new();
+    GzipEncoder::new(body)
+        .read_to_end(&mut encoded)
+        .await
+        .unwrap();
+    encoded
+}
+
+fn service(url: String, max_response_size: Option<usize>) -> ElongService {
+    account_builder()
+        .base_url(url)
+        .retry_policy(RetryPolicy::none())
+        .max_response_size(max_response_size)
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_plain_response() {
+    let (url, received) = serve(INCR_ID_BODY.as_bytes().to_vec(), false).await;
+
+    let response = service(url, None)
+        .get_incr_id(IncrIdRequest::default())
+        .await
+        .unwrap();
+    assert_eq!(response.result.unwrap().last_id, 42);
+    assert!(received.lock().unwrap().starts_with("GET /rest?"));
+}
+
+#[tokio::test]
+async fn test_gzip_response() {
+    let (url, _) = serve(gzip(INCR_ID_BODY.as_bytes()).await, true).await;
+
+    let response = service(url, Some(1024))
+        .get_incr_id(IncrIdRequest::default())
+        .await
+        .unwrap();
+    assert_eq!(response.result.unwrap().last_id, 42);
+}
+
+#[tokio::test]
+async fn test_response_too_large() {
+    let (url, _) = serve(INCR_ID_BODY.as_bytes().to_vec(), false).await;
+
+    let err = service(url, Some(16))
+        .get_incr_id(IncrIdRequest::default())
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ElongError::ResponseTooLarge(16)));
+}
+
+#[tokio::test]
+async fn test_gzip_response_too_large_after_decoding() {
+    // 压缩后很小，解压后超过上限
+    let body = format!(
+        r#"{{"Code":"0","Result":{{"LastId":1}},"Guid":"{}"}}"#,
+        "g".repeat(4096)
+    );
+    let (url, _) = serve(gzip(body.as_bytes()).await, true).await;
+
+    let err = service(url, Some(1024))
+        .get_incr_id(IncrIdRequest::default())
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ElongError::ResponseTooLarge(1024)));
+}
+
+#[tokio::test]
+async fn test_post_form_body() {
+    let (url, received) = serve(INCR_ID_BODY.as_bytes().to_vec(), false).await;
+
+    let service = account_builder()
+        .base_url(url)
+        .post_method(ApiMethod::IncrId)
+        .build()
+        .unwrap();
+    service.get_incr_id(IncrIdRequest::default()).await.unwrap();
+
+    let received = received.lock().unwrap();
+    assert!(received.starts_with("POST /rest HTTP/1.1"));
+    assert!(received.contains("application/x-www-form-urlencoded"));
+    assert!(received.contains("method=hotel.incr.id"));
+}
+
+struct TraceHeader;
+
+#[async_trait::async_trait]
+impl Middleware for TraceHeader {
+    async fn handle(
+        &self,
+        request: &mut MiddlewareRequest,
+        next: Next<'_>,
+    ) -> Result<TransportResponse, ElongError> {
+        request.add_header("X-Trace-Id", "abc");
+        next.run(request).await
+    }
+}
+
+#[tokio::test]
+async fn test_middleware_headers_sent() {
+    let (url, received) = serve(INCR_ID_BODY.as_bytes().to_vec(), false).await;
+
+    let service = account_builder()
+        .base_url(url)
+        .middleware(Arc::new(TraceHeader))
+        .build()
+        .unwrap();
+    service.get_incr_id(IncrIdRequest::default()).await.unwrap();
+
+    assert!(received.lock().unwrap().contains("x-trace-id: abc"));
+}
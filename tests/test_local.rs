@@ -0,0 +1,191 @@
+mod common;
+
+use std::sync::Arc;
+
+use common::{builder, RecordingTransport, INCR_ID_BODY};
+use elong_sdk::elong::{builder::ElongServiceBuilder, endpoints::ApiMethod, service::ElongService};
+use elong_sdk::request::incr_id::IncrIdRequest;
+use elong_sdk::request::static_city::StaticCityRequest;
+use elong_sdk::response::api_response::ElongResultExt;
+use elong_sdk::response::{
+    dictionary, hotel_detail_response, order_detail, static_brand, static_group, static_info,
+};
+use elong_sdk::Elong;
+use serde_json::json;
+
+fn service(
+    body: &'static str,
+    configure: impl FnOnce(ElongServiceBuilder) -> ElongServiceBuilder,
+) -> (ElongService, Arc<RecordingTransport>) {
+    let transport = RecordingTransport::body(body);
+    (
+        configure(builder(transport.clone())).build().unwrap(),
+        transport,
+    )
+}
+
+/// 最近一次请求的 `Version` 与 `Local`
+fn version_and_local(transport: &RecordingTransport) -> (String, String) {
+    let payload = transport.last().payload();
+    (
+        payload["Version"].as_str().unwrap().to_string(),
+        payload["Local"].as_str().unwrap().to_string(),
+    )
+}
+
+#[tokio::test]
+async fn test_default_version_and_local() {
+    let (service, transport) = service(INCR_ID_BODY, |b| b);
+
+    service.get_incr_id(IncrIdRequest::default()).await.unwrap();
+    assert_eq!(
+        version_and_local(&transport),
+        ("1.62".to_string(), "zh-CN".to_string())
+    );
+}
+
+#[tokio::test]
+async fn test_client_version_and_local() {
+    let (service, transport) = service(INCR_ID_BODY, |b| {
+        b.version("1.5")
+            .local("en-US")
+            .method_version(ApiMethod::StaticCity, "1.1")
+    });
+
+    service.get_incr_id(IncrIdRequest::default()).await.unwrap();
+    assert_eq!(
+        version_and_local(&transport),
+        ("1.5".to_string(), "en-US".to_string())
+    );
+    assert_eq!(service.client.version_for(ApiMethod::StaticCity), "1.1");
+}
+
+#[tokio::test]
+async fn test_per_call_override() {
+    let (service, transport) =
+        service(INCR_ID_BODY, |b| b.method_version(ApiMethod::IncrId, "1.1"));
+
+    service
+        .with_local("en-US")
+        .get_incr_id(IncrIdRequest::default())
+        .await
+        .unwrap();
+    assert_eq!(
+        version_and_local(&transport),
+        ("1.1".to_string(), "en-US".to_string())
+    );
+
+    service
+        .with_version("2.0")
+        .get_incr_id(IncrIdRequest::default())
+        .await
+        .unwrap();
+    assert_eq!(
+        version_and_local(&transport),
+        ("2.0".to_string(), "zh-CN".to_string())
+    );
+
+    // 视图不影响原服务
+    service.get_incr_id(IncrIdRequest::default()).await.unwrap();
+    assert_eq!(
+        version_and_local(&transport),
+        ("1.1".to_string(), "zh-CN".to_string())
+    );
+}
+
+#[tokio::test]
+async fn test_english_names_optional() {
+    let (service, _) = service(
+        r#"{"Code":"0","Result":{"Count":1,"Citys":[{
+            "CityId":"0101","CityName":"北京","CityNameEn":"Beijing",
+            "CityLongitude":"116.4","CityLatitude":"39.9","CityParentID":"0",
+            "ProvinceId":"01","ProvinceName":"北京",
+            "CountryId":"1","CountryName":"中国","CountryCode":"CN",
+            "Locations":[{"LocationID":"1","LocationName":"朝阳区","LocationType":1}]
+        }]},"Guid":"g"}"#,
+        |b| b.local("en-US"),
+    );
+
+    let cities = service
+        .get_static_city(StaticCityRequest::default())
+        .await
+        .into_data()
+        .unwrap();
+    let city = &cities.citys[0];
+    assert_eq!(city.province_name_en, None);
+    assert_eq!(city.name(service.client.local()), "Beijing");
+    let location = &city.locations.as_ref().unwrap()[0];
+    assert_eq!(location.name("en-US"), "朝阳区");
+}
+
+#[tokio::test]
+async fn test_payload_escapes_local_and_keeps_version_string() {
+    let (service, transport) = service(INCR_ID_BODY, |b| b.version("2.0").local(r#"en"US\"#));
+
+    service.get_incr_id(IncrIdRequest::default()).await.unwrap();
+    assert_eq!(
+        version_and_local(&transport),
+        ("2.0".to_string(), r#"en"US\"#.to_string())
+    );
+}
+
+#[test]
+fn test_localized_hotel_and_room_names() {
+    let room: static_info::Room = serde_json::from_value(
+        json!({"RoomID": "1", "RoomName": "大床房", "RoomNameEn": "King Room"}),
+    )
+    .unwrap();
+    assert_eq!(room.name("en-US"), "King Room");
+    assert_eq!(room.name("zh-CN"), "大床房");
+
+    let room: hotel_detail_response::Room =
+        serde_json::from_value(json!({"RoomId": "1", "Name": "大床房"})).unwrap();
+    assert_eq!(room.name("en-US"), "大床房");
+
+    // 英文环境下国内酒店可能不返回英文名
+    let hotel: order_detail::OrderHotel = serde_json::from_value(json!({
+        "HotelId": "1", "Name": "北京饭店", "Address": "长安街", "CityName": "北京"
+    }))
+    .unwrap();
+    assert_eq!(hotel.hotel_english_name, None);
+    assert_eq!(hotel.name("en-US"), "北京饭店");
+}
+
+#[test]
+fn test_localized_brand_group_facility_and_zone_names() {
+    let brand: static_brand::Brand = serde_json::from_value(json!({
+        "brandId": 1, "groupId": 2, "brandName": "如家", "brandNameEn": "Home Inn",
+        "brandNameDetail": "如家酒店", "status": 0, "optTime": "2024-01-01 00:00:00"
+    }))
+    .unwrap();
+    assert_eq!(brand.name("en-US"), "Home Inn");
+    assert_eq!(brand.full_name("en-US"), Some("如家酒店"));
+
+    let group: static_group::Group = serde_json::from_value(json!({
+        "groupId": 2, "groupName": "首旅如家", "groupNameDetail": "首旅如家酒店集团",
+        "groupNameEn": "BTG Homeinns", "groupStatus": 0, "optTime": "2024-01-01 00:00:00"
+    }))
+    .unwrap();
+    assert_eq!(group.name("en-US"), "BTG Homeinns");
+    assert_eq!(group.full_name("en-US"), "首旅如家酒店集团");
+
+    let facility: dictionary::Facility = serde_json::from_value(json!({
+        "FacilityId": 1, "FacilityName": "停车场", "FacilityNameEn": "Parking",
+        "FacilityTypeId": 1, "FacilityTypeName": "交通"
+    }))
+    .unwrap();
+    assert_eq!(facility.name("en-US"), "Parking");
+    assert_eq!(facility.type_name("en-US"), "交通");
+
+    let hotel: static_info::Hotel = serde_json::from_value(json!({
+        "HotelId": "1", "HotelName": "北京饭店",
+        "DistrictName": "东城区", "DistrictNameEn": "Dongcheng",
+        "BusinessZoneName": "王府井", "BrandName": "如家", "BrandNameEn": ""
+    }))
+    .unwrap();
+    assert_eq!(hotel.district_name("en-US"), Some("Dongcheng"));
+    assert_eq!(hotel.district_name("zh-CN"), Some("东城区"));
+    assert_eq!(hotel.business_zone_name("en-US"), Some("王府井"));
+    assert_eq!(hotel.brand_name("en-US"), Some("如家"));
+    assert_eq!(hotel.group_name("en-US"), None);
+}
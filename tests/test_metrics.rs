@@ -0,0 +1,159 @@
+mod common;
+
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use common::{builder, RecordingTransport, INCR_ID_BODY};
+use elong_sdk::elong::{
+    endpoints::ApiMethod,
+    error::ElongError,
+    metrics::{CallMetrics, CallOutcome, InMemoryMetrics, MetricsRecorder},
+    retry::RetryPolicy,
+    service::ElongService,
+};
+use elong_sdk::network::transport::TransportResponse;
+use elong_sdk::request::api_request::{ApiCall, BaseRequest};
+use elong_sdk::request::incr_id::IncrIdRequest;
+use elong_sdk::request::incr_inv::IncrInvRequest;
+use elong_sdk::Elong;
+
+const BUSY_BODY: &str = r#"{"Code":"H000997|系统繁忙","Guid":"g"}"#;
+
+/// 按顺序返回预设的响应体或 HTTP 状态码，用完后重复最后一个
+fn service(
+    responses: Vec<Result<&'static str, u16>>,
+    metrics: Arc<dyn MetricsRecorder>,
+) -> ElongService {
+    let transport =
+        RecordingTransport::new(
+            move |call, _| match responses[call.min(responses.len() - 1)] {
+                Ok(body) => Ok(TransportResponse::new(body)),
+                Err(status) => Err(ElongError::HttpStatus(status)),
+            },
+        );
+    builder(transport)
+        .retry_policy(RetryPolicy::default().backoff(Duration::ZERO, Duration::ZERO))
+        .metrics(metrics)
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_in_memory_metrics() {
+    let metrics = Arc::new(InMemoryMetrics::new());
+    let service = service(
+        vec![Ok(BUSY_BODY), Ok(INCR_ID_BODY), Ok(INCR_ID_BODY), Err(502)],
+        metrics.clone(),
+    );
+
+    // 第一次系统繁忙后重试成功
+    service.get_incr_id(IncrIdRequest::default()).await.unwrap();
+    service.get_incr_id(IncrIdRequest::default()).await.unwrap();
+    // 之后一直 502
+    service
+        .get_incr_inv(IncrInvRequest::default())
+        .await
+        .unwrap_err();
+
+    let snapshot = metrics.snapshot();
+    let incr_id = &snapshot.methods["hotel.incr.id"];
+    assert_eq!(incr_id.calls, 2);
+    assert_eq!(incr_id.successes, 2);
+    assert_eq!(incr_id.retries, 1);
+    assert_eq!(incr_id.codes["0"], 2);
+    assert_eq!(incr_id.latency.count(), 2);
+    assert_eq!(
+        incr_id.response_bytes as usize,
+        BUSY_BODY.len() + INCR_ID_BODY.len() * 2
+    );
+    // 请求与响应字节数均按尝试累计
+    let request_len = IncrIdRequest::default().to_json().unwrap().len();
+    assert_eq!(incr_id.request_bytes as usize, request_len * 3);
+    assert_eq!(incr_id.error_rate(), 0.0);
+
+    let incr_inv = &snapshot.methods["hotel.incr.inv"];
+    assert_eq!(incr_inv.calls, 1);
+    assert_eq!(incr_inv.errors, 1);
+    assert_eq!(incr_inv.retries, 2);
+    assert!(incr_inv.codes.is_empty());
+    assert_eq!(incr_inv.error_rate(), 1.0);
+
+    let json = serde_json::to_value(&snapshot).unwrap();
+    assert_eq!(json["methods"]["hotel.incr.id"]["calls"], 2);
+
+    metrics.reset();
+    assert!(metrics.snapshot().methods.is_empty());
+}
+
+#[tokio::test]
+async fn test_api_error_codes_counted() {
+    let metrics = Arc::new(InMemoryMetrics::new());
+    let service = service(
+        vec![Ok(r#"{"Code":"H001030|订单不存在","Guid":"g"}"#)],
+        metrics.clone(),
+    );
+
+    service
+        .get_incr_id(IncrIdRequest::default())
+        .await
+        .unwrap_err();
+
+    let snapshot = metrics.snapshot();
+    let incr_id = &snapshot.methods["hotel.incr.id"];
+    assert_eq!(incr_id.api_errors, 1);
+    assert_eq!(incr_id.codes["H001030"], 1);
+}
+
+/// 自定义记录器收到的指标
+#[derive(Default)]
+struct Collect(Mutex<Vec<CallMetrics>>);
+
+impl MetricsRecorder for Collect {
+    fn record(&self, metrics: &CallMetrics) {
+        self.0.lock().unwrap().push(metrics.clone());
+    }
+}
+
+#[tokio::test]
+async fn test_custom_recorder() {
+    let collect = Arc::new(Collect::default());
+    let service = service(vec![Ok(INCR_ID_BODY)], collect.clone());
+
+    service.get_incr_id(IncrIdRequest::default()).await.unwrap();
+
+    let calls = collect.0.lock().unwrap();
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].method, ApiMethod::IncrId);
+    assert_eq!(calls[0].outcome, CallOutcome::Success);
+    assert_eq!(calls[0].attempts, 1);
+}
+
+/// 无法序列化的请求
+struct Unserializable;
+
+impl BaseRequest for Unserializable {
+    fn to_json(&self) -> Result<String, ElongError> {
+        Err(ElongError::ConfigError("unserializable".to_string()))
+    }
+}
+
+impl ApiCall for Unserializable {
+    type Response = serde_json::Value;
+    const METHOD: ApiMethod = ApiMethod::IncrId;
+}
+
+#[tokio::test]
+async fn test_serialize_error_recorded() {
+    let collect = Arc::new(Collect::default());
+    let service = service(vec![Ok(INCR_ID_BODY)], collect.clone());
+
+    service.call(Unserializable).await.unwrap_err();
+
+    let calls = collect.0.lock().unwrap();
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].outcome, CallOutcome::Error);
+    assert_eq!(calls[0].attempts, 0);
+    assert_eq!(calls[0].request_bytes, 0);
+}
@@ -0,0 +1,185 @@
+mod common;
+
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use common::{builder, RecordingTransport, INCR_ID_BODY};
+use elong_sdk::elong::{
+    endpoints::ApiMethod,
+    error::ElongError,
+    middleware::{Middleware, MiddlewareRequest, Next},
+    retry::RetryPolicy,
+    service::ElongService,
+};
+use elong_sdk::network::transport::TransportResponse;
+use elong_sdk::request::incr_id::IncrIdRequest;
+use elong_sdk::Elong;
+
+/// 前 `failures` 次返回 502
+fn failing(failures: usize) -> Arc<RecordingTransport> {
+    RecordingTransport::new(move |call, _| {
+        if call < failures {
+            return Err(ElongError::HttpStatus(502));
+        }
+        Ok(TransportResponse::new(INCR_ID_BODY))
+    })
+}
+
+/// 记录执行顺序与看到的请求和结果
+struct Recorder {
+    name: &'static str,
+    log: Arc<Mutex<Vec<String>>>,
+}
+
+#[async_trait]
+impl Middleware for Recorder {
+    async fn handle(
+        &self,
+        request: &mut MiddlewareRequest,
+        next: Next<'_>,
+    ) -> Result<TransportResponse, ElongError> {
+        let name = self.name;
+        self.log.lock().unwrap().push(format!(
+            "{name} before {} {} {}",
+            request.method.name(),
+            request.request_json().contains("LastTime"),
+            request.params().contains("method=hotel.incr.id"),
+        ));
+        request.add_header("X-Middleware", name);
+        let result = next.run(request).await;
+        self.log
+            .lock()
+            .unwrap()
+            .push(format!("{name} after ok={}", result.is_ok()));
+        result
+    }
+}
+
+/// 直接返回缓存的响应
+struct Cache;
+
+#[async_trait]
+impl Middleware for Cache {
+    async fn handle(
+        &self,
+        request: &mut MiddlewareRequest,
+        next: Next<'_>,
+    ) -> Result<TransportResponse, ElongError> {
+        if request.method == ApiMethod::IncrId {
+            return Ok(TransportResponse::new(
+                r#"{"Code":"0","Result":{"LastId":99},"Guid":"cached"}"#,
+            ));
+        }
+        next.run(request).await
+    }
+}
+
+/// 改写响应
+struct Rewrite;
+
+#[async_trait]
+impl Middleware for Rewrite {
+    async fn handle(
+        &self,
+        request: &mut MiddlewareRequest,
+        next: Next<'_>,
+    ) -> Result<TransportResponse, ElongError> {
+        let mut response = next.run(request).await?;
+        let body = String::from_utf8(response.body).unwrap();
+        response.body = body.replace("\"LastId\":1", "\"LastId\":2").into_bytes();
+        Ok(response)
+    }
+}
+
+fn service(
+    transport: Arc<RecordingTransport>,
+    middlewares: Vec<Arc<dyn Middleware>>,
+) -> ElongService {
+    let mut builder = builder(transport).retry_policy(
+        RetryPolicy::default()
+            .jitter(false)
+            .backoff(Default::default(), Default::default()),
+    );
+    for middleware in middlewares {
+        builder = builder.middleware(middleware);
+    }
+    builder.build().unwrap()
+}
+
+#[tokio::test]
+async fn test_middleware_order_and_headers() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let transport = failing(0);
+    let service = service(
+        transport.clone(),
+        vec![
+            Arc::new(Recorder {
+                name: "a",
+                log: log.clone(),
+            }),
+            Arc::new(Recorder {
+                name: "b",
+                log: log.clone(),
+            }),
+        ],
+    );
+
+    service.get_incr_id(IncrIdRequest::default()).await.unwrap();
+
+    assert_eq!(
+        *log.lock().unwrap(),
+        vec![
+            "a before hotel.incr.id true true",
+            "b before hotel.incr.id true true",
+            "b after ok=true",
+            "a after ok=true",
+        ]
+    );
+    assert_eq!(
+        transport.last().headers,
+        vec![
+            ("X-Middleware".to_string(), "a".to_string()),
+            ("X-Middleware".to_string(), "b".to_string()),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_middleware_short_circuit() {
+    let transport = failing(0);
+    let service = service(transport.clone(), vec![Arc::new(Cache)]);
+
+    let response = service.get_incr_id(IncrIdRequest::default()).await.unwrap();
+    assert_eq!(response.result.unwrap().last_id, 99);
+    assert_eq!(response.guid.as_deref(), Some("cached"));
+    assert_eq!(transport.calls(), 0);
+}
+
+#[tokio::test]
+async fn test_middleware_modifies_response() {
+    let transport = failing(0);
+    let service = service(transport, vec![Arc::new(Rewrite)]);
+
+    let response = service.get_incr_id(IncrIdRequest::default()).await.unwrap();
+    assert_eq!(response.result.unwrap().last_id, 2);
+}
+
+#[tokio::test]
+async fn test_middleware_sees_errors_and_retries() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let transport = failing(1);
+    let service = service(
+        transport,
+        vec![Arc::new(Recorder {
+            name: "a",
+            log: log.clone(),
+        })],
+    );
+
+    service.get_incr_id(IncrIdRequest::default()).await.unwrap();
+
+    let log = log.lock().unwrap();
+    assert_eq!(log.len(), 4);
+    assert_eq!(log[1], "a after ok=false");
+    assert_eq!(log[3], "a after ok=true");
+}
@@ -0,0 +1,125 @@
+#![cfg(feature = "testing")]
+
+use std::sync::{
+    atomic::{AtomicI64, Ordering},
+    Arc,
+};
+
+use elong_sdk::elong::{error::ElongError, result_code::ElongResultCode, testing::MockElong};
+use elong_sdk::request::incr_id::IncrIdRequest;
+use elong_sdk::request::order_create::OrderCreateRequest;
+use elong_sdk::request::order_detail::OrderDetailRequest;
+use elong_sdk::response::incr_id::IncrIdResponse;
+use elong_sdk::response::order_create::OrderCreateResponse;
+use elong_sdk::response::order_detail::OrderDetailReponse;
+use elong_sdk::Elong;
+
+/// 业务代码只依赖 `Elong`
+async fn book<E: Elong>(elong: &E, affiliate_confirmation_id: &str) -> Result<String, ElongError> {
+    let created = elong
+        .order_create(OrderCreateRequest {
+            affiliate_confirmation_id: affiliate_confirmation_id.to_string(),
+            total_price: 100.0,
+            ..Default::default()
+        })
+        .await?
+        .into_result()?;
+    let detail = elong
+        .order_detail(OrderDetailRequest {
+            order_id: created.order_id,
+            ..Default::default()
+        })
+        .await?
+        .into_result()?;
+    Ok(detail.status)
+}
+
+#[tokio::test]
+async fn test_programmed_responses_and_recorded_calls() {
+    let mock = MockElong::new();
+    mock.on_order_create(|req| {
+        assert_eq!(req.total_price, 100.0);
+        MockElong::ok(OrderCreateResponse {
+            order_id: 42,
+            ..Default::default()
+        })
+    })
+    .on_order_detail(|req| {
+        MockElong::ok(OrderDetailReponse {
+            order_id: req.order_id,
+            status: "A".to_string(),
+            ..Default::default()
+        })
+    });
+
+    assert_eq!(book(&mock, "A-1").await.unwrap(), "A");
+
+    let calls = mock.calls();
+    let methods: Vec<_> = calls.iter().map(|call| call.method).collect();
+    assert_eq!(methods, ["order_create", "order_detail"]);
+    assert_eq!(
+        mock.calls_to("order_create")[0]["AffiliateConfirmationId"],
+        "A-1"
+    );
+    assert_eq!(mock.calls_to("order_detail")[0]["OrderId"], 42);
+    assert_eq!(mock.configured(), ["order_create", "order_detail"]);
+}
+
+#[tokio::test]
+async fn test_programmed_errors() {
+    let mock = MockElong::new();
+    mock.on_order_create(|_| MockElong::api_error("H001022|房量不足"));
+
+    let err = book(&mock, "A-1").await.unwrap_err();
+    assert_eq!(
+        err.api_error().unwrap().result_code(),
+        ElongResultCode::InventoryUnavailable
+    );
+    assert_eq!(err.api_error().unwrap().method, "hotel.order.create");
+    assert_eq!(mock.call_count("order_create"), 1);
+    assert_eq!(mock.call_count("order_detail"), 0);
+}
+
+#[tokio::test]
+async fn test_unconfigured_method_is_a_clear_error() {
+    let mock = MockElong::new();
+    let err = mock
+        .get_incr_id(IncrIdRequest::default())
+        .await
+        .unwrap_err();
+    match err {
+        ElongError::ConfigError(message) => {
+            assert!(message.contains("get_incr_id"));
+            assert!(message.contains("on_get_incr_id"));
+        }
+        other => panic!("unexpected error {other:?}"),
+    }
+    assert_eq!(mock.call_count("get_incr_id"), 1);
+}
+
+#[tokio::test]
+async fn test_handlers_can_keep_state_and_be_replaced() {
+    let mock = Arc::new(MockElong::new());
+    let next = Arc::new(AtomicI64::new(1));
+    let counter = next.clone();
+    mock.on_get_incr_id(move |_| {
+        MockElong::ok(IncrIdResponse {
+            last_id: counter.fetch_add(1, Ordering::SeqCst),
+        })
+    });
+
+    for expected in 1..=3 {
+        let response = mock.get_incr_id(IncrIdRequest::default()).await.unwrap();
+        assert_eq!(response.result.unwrap().last_id, expected);
+    }
+
+    mock.on_get_incr_id(|_| MockElong::api_error("H000997|系统繁忙"));
+    let err = mock
+        .get_incr_id(IncrIdRequest::default())
+        .await
+        .unwrap_err();
+    assert!(err.is_retryable());
+
+    mock.reset_calls();
+    assert!(mock.calls().is_empty());
+}
@@ -0,0 +1,261 @@
+#![cfg(feature = "mock-server")]
+
+use std::{
+    fs,
+    time::{Duration, Instant},
+};
+
+use elong_sdk::elong::{
+    credentials::Credentials, endpoints::ApiMethod, error::ElongError, retry::RetryPolicy,
+    service::ElongService,
+};
+use elong_sdk::mock::server::{MockResponse, MockServer, MockServerBuilder};
+use elong_sdk::network::transport::HttpMethod;
+use elong_sdk::request::api_request::{ApiRequestPayload, ApiSignedRequest};
+use elong_sdk::request::incr_id::IncrIdRequest;
+use elong_sdk::Elong;
+use serde_json::json;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+
+const LAST_ID: &str = r#"{"Code":"0","Result":{"LastId":42},"Guid":"g"}"#;
+
+fn server() -> MockServerBuilder {
+    MockServer::builder().account(Credentials::new("mock_user", "mock_key", "mock_secret"))
+}
+
+fn service(server: &MockServer, app_secret: &str) -> ElongService {
+    ElongService::builder()
+        .credentials("mock_user", "mock_key", app_secret)
+        .base_url(server.url())
+        .retry_policy(RetryPolicy::none())
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_params_round_trip() {
+    let signed = ApiSignedRequest::new_at(
+        "user".to_string(),
+        "key",
+        "secret",
+        "hotel.incr.id".to_string(),
+        ApiRequestPayload {
+            version: "1.62".to_string(),
+            local: "zh-CN".to_string(),
+            request: r#"{"LastTime":"2024-01-01 00:00:00"}"#.to_string(),
+        },
+        1_700_000_000,
+    )
+    .unwrap();
+    let parsed = ApiSignedRequest::from_params(&signed.to_params()).unwrap();
+    assert_eq!(parsed.data, signed.data);
+    assert_eq!(parsed.signature, signed.signature);
+    assert!(parsed.verify("key", "secret"));
+    assert!(!parsed.verify("key", "other"));
+    assert!(ApiSignedRequest::from_params("user=a&method=b").is_none());
+}
+
+#[tokio::test]
+async fn test_serves_fixture_with_valid_signature() {
+    let server = server()
+        .fixture("hotel.incr.id", MockResponse::json(LAST_ID))
+        .start()
+        .await
+        .unwrap();
+    let response = service(&server, "mock_secret")
+        .get_incr_id(IncrIdRequest::default())
+        .await
+        .unwrap();
+    assert_eq!(response.result.unwrap().last_id, 42);
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "hotel.incr.id");
+    assert_eq!(requests[0].http_method, HttpMethod::Get);
+    assert!(requests[0].signature_valid);
+    assert!(requests[0].request.get("LastTime").is_some());
+}
+
+#[tokio::test]
+async fn test_rejects_invalid_signature() {
+    let server = server()
+        .fixture("hotel.incr.id", MockResponse::json(LAST_ID))
+        .start()
+        .await
+        .unwrap();
+    let err = service(&server, "wrong_secret")
+        .get_incr_id(IncrIdRequest::default())
+        .await
+        .unwrap_err();
+    assert!(err.is_signature_error());
+    assert!(!server.requests()[0].signature_valid);
+}
+
+#[tokio::test]
+async fn test_rejects_unknown_user_and_method() {
+    let server = server().start().await.unwrap();
+    let err = ElongService::builder()
+        .credentials("someone_else", "mock_key", "mock_secret")
+        .base_url(server.url())
+        .build()
+        .unwrap()
+        .get_incr_id(IncrIdRequest::default())
+        .await
+        .unwrap_err();
+    assert!(err.is_auth_error());
+
+    let err = service(&server, "mock_secret")
+        .get_incr_id(IncrIdRequest::default())
+        .await
+        .unwrap_err();
+    assert_eq!(err.api_error().unwrap().code, "H000005");
+}
+
+#[tokio::test]
+async fn test_gzip_and_latency() {
+    let server = server()
+        .gzip(true)
+        .latency(Duration::from_millis(100))
+        .fixture(
+            "hotel.incr.id",
+            MockResponse::json(LAST_ID).with_latency(Duration::from_millis(100)),
+        )
+        .start()
+        .await
+        .unwrap();
+    let started = Instant::now();
+    let response = service(&server, "mock_secret")
+        .get_incr_id(IncrIdRequest::default())
+        .await
+        .unwrap();
+    assert!(started.elapsed() >= Duration::from_millis(200));
+    assert_eq!(response.result.unwrap().last_id, 42);
+}
+
+#[tokio::test]
+async fn test_injected_errors_in_sequence() {
+    let server = server()
+        .fixture(
+            "hotel.incr.id",
+            MockResponse::http_error(502, "<html><body>Bad Gateway</body></html>"),
+        )
+        .fixture("hotel.incr.id", MockResponse::api_error("H000997|系统繁忙"))
+        .fixture("hotel.incr.id", MockResponse::json(LAST_ID))
+        .start()
+        .await
+        .unwrap();
+    let service = service(&server, "mock_secret");
+
+    let err = service
+        .get_incr_id(IncrIdRequest::default())
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ElongError::HttpStatus(502)));
+    let err = service
+        .get_incr_id(IncrIdRequest::default())
+        .await
+        .unwrap_err();
+    assert!(err.is_retryable());
+    for _ in 0..2 {
+        let response = service.get_incr_id(IncrIdRequest::default()).await.unwrap();
+        assert_eq!(response.result.unwrap().last_id, 42);
+    }
+}
+
+#[tokio::test]
+async fn test_post_and_fixture_dir() {
+    let dir = std::env::temp_dir().join(format!("elong-mock-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("hotel.incr.id.json"), LAST_ID).unwrap();
+    let server = server().fixture_dir(&dir).start().await.unwrap();
+
+    let service = ElongService::builder()
+        .credentials("mock_user", "mock_key", "mock_secret")
+        .base_url(server.url())
+        .post_method(ApiMethod::IncrId)
+        .build()
+        .unwrap();
+    let response = service.get_incr_id(IncrIdRequest::default()).await.unwrap();
+    assert_eq!(response.result.unwrap().last_id, 42);
+    assert_eq!(server.requests()[0].http_method, HttpMethod::Post);
+    assert!(server.requests()[0].signature_valid);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn test_ok_response_and_clock_skew() {
+    let server = server()
+        .max_clock_skew(Duration::from_secs(300))
+        .fixture("hotel.incr.id", MockResponse::ok(json!({"LastId": 7})))
+        .start()
+        .await
+        .unwrap();
+    let response = service(&server, "mock_secret")
+        .get_incr_id(IncrIdRequest::default())
+        .await
+        .unwrap();
+    assert_eq!(response.result.unwrap().last_id, 7);
+}
+
+/// 发送原始 HTTP 请求，返回完整响应
+async fn raw(server: &MockServer, request: &str) -> String {
+    let mut stream = TcpStream::connect(server.addr()).await.unwrap();
+    stream.write_all(request.as_bytes()).await.unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).await.unwrap();
+    response
+}
+
+#[tokio::test]
+async fn test_rejects_oversized_body() {
+    let server = server().start().await.unwrap();
+    let response = raw(
+        &server,
+        "POST / HTTP/1.1\r\nContent-Length: 4294967295\r\n\r\n",
+    )
+    .await;
+    assert!(response.starts_with("HTTP/1.1 413"), "{response}");
+    assert!(server.requests().is_empty());
+}
+
+#[tokio::test]
+async fn test_fixture_dir_rejects_path_traversal() {
+    let dir = std::env::temp_dir().join(format!("elong-mock-traversal-{}", std::process::id()));
+    fs::create_dir_all(dir.join("fixtures")).unwrap();
+    fs::write(dir.join("secret.json"), LAST_ID).unwrap();
+    let server = server()
+        .fixture_dir(dir.join("fixtures"))
+        .start()
+        .await
+        .unwrap();
+
+    let signed = ApiSignedRequest::new_at(
+        "mock_user".to_string(),
+        "mock_key",
+        "mock_secret",
+        "../secret".to_string(),
+        ApiRequestPayload {
+            version: "1.62".to_string(),
+            local: "zh-CN".to_string(),
+            request: "{}".to_string(),
+        },
+        chrono::Utc::now().timestamp(),
+    )
+    .unwrap();
+    let body = signed.to_params();
+    let response = raw(
+        &server,
+        &format!(
+            "POST / HTTP/1.1\r\nContent-Length: {}\r\n\r\n{body}",
+            body.len()
+        ),
+    )
+    .await;
+    assert!(server.requests()[0].signature_valid);
+    assert!(response.contains("H000005"), "{response}");
+    assert!(!response.contains("LastId"), "{response}");
+
+    fs::remove_dir_all(&dir).unwrap();
+}
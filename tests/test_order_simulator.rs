@@ -0,0 +1,420 @@
+#![cfg(feature = "mock-server")]
+
+use std::{sync::Arc, time::Duration};
+
+use chrono::{TimeZone, Utc};
+use elong_sdk::elong::{
+    clock::FixedClock, credentials::Credentials, error::ElongError, result_code::ElongResultCode,
+    retry::RetryPolicy, service::ElongService,
+};
+use elong_sdk::mock::{
+    orders::{
+        OrderSimulator, PAY_STATUS_PROCESSING, PAY_STATUS_SUCCESS, PAY_STATUS_WAITING,
+        STATUS_CANCELLED, STATUS_CHECKED_IN, STATUS_CONFIRMED, STATUS_NEW, STATUS_REVIEWED,
+    },
+    server::{MockResponse, MockServer},
+};
+use elong_sdk::request::incr_order::IncrOrderRequest;
+use elong_sdk::request::order_cancel::OrderCancelRequest;
+use elong_sdk::request::order_create::{Contact, Customer, OrderCreateRequest, OrderRoom};
+use elong_sdk::request::order_detail::OrderDetailRequest;
+use elong_sdk::request::order_list::OrderListRequest;
+use elong_sdk::request::order_pay::OrderPayRequest;
+use elong_sdk::request::order_pay_confirm::OrderPayConfirmRequest;
+use elong_sdk::request::order_promote::OrderPromoteRequest;
+use elong_sdk::Elong;
+
+const TOTAL_PRICE: f64 = 284.59;
+
+struct Harness {
+    service: ElongService,
+    clock: Arc<FixedClock>,
+    orders: Arc<OrderSimulator>,
+    server: MockServer,
+}
+
+/// 北京时间 2025-06-20 10:00 下单，入住 2025-06-23，免费取消截止 2025-06-22 18:00
+async fn harness(configure: impl FnOnce(OrderSimulator) -> OrderSimulator) -> Harness {
+    let clock = Arc::new(FixedClock::new(
+        Utc.with_ymd_and_hms(2025, 6, 20, 2, 0, 0).unwrap(),
+    ));
+    let orders = Arc::new(configure(
+        OrderSimulator::new()
+            .with_clock(clock.clone())
+            .schedule(vec![
+                (Duration::from_secs(30), STATUS_REVIEWED),
+                (Duration::from_secs(60), STATUS_CONFIRMED),
+            ]),
+    ));
+    let server = MockServer::builder()
+        .account(Credentials::new("mock_user", "mock_key", "mock_secret"))
+        .order_simulator(orders.clone())
+        .start()
+        .await
+        .unwrap();
+    let service = ElongService::builder()
+        .credentials("mock_user", "mock_key", "mock_secret")
+        .base_url(server.url())
+        .retry_policy(RetryPolicy::none())
+        .build()
+        .unwrap();
+    Harness {
+        service,
+        clock,
+        orders,
+        server,
+    }
+}
+
+fn order(affiliate_confirmation_id: &str, payment_type: &str) -> OrderCreateRequest {
+    OrderCreateRequest {
+        affiliate_confirmation_id: affiliate_confirmation_id.to_string(),
+        hotel_id: "28005348".to_string(),
+        room_type_id: "0003".to_string(),
+        rate_plan_id: 416853117,
+        arrival_date: "2025-06-23".to_string(),
+        departure_date: "2025-06-24".to_string(),
+        payment_type: payment_type.to_string(),
+        number_of_rooms: 1,
+        number_of_customers: 1,
+        earliest_arrival_time: "2025-06-23 14:00:00".to_string(),
+        latest_arrival_time: "2025-06-23 18:00:00".to_string(),
+        currency_code: "RMB".to_string(),
+        total_price: TOTAL_PRICE,
+        customer_ip_address: "127.0.0.1".to_string(),
+        confirmation_type: "SMS_cn".to_string(),
+        order_rooms: vec![OrderRoom {
+            customers: vec![Customer {
+                name: "林立体".to_string(),
+                gender: "Unknown".to_string(),
+                nationality: "中国".to_string(),
+                ..Default::default()
+            }],
+        }],
+        contact: Contact {
+            name: "林立体".to_string(),
+            mobile_area_code: "86".to_string(),
+            mobile: "18301221126".to_string(),
+            gender: "Unknown".to_string(),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+impl Harness {
+    async fn create(&self, affiliate_confirmation_id: &str, payment_type: &str) -> i64 {
+        self.service
+            .order_create(order(affiliate_confirmation_id, payment_type))
+            .await
+            .unwrap()
+            .into_result()
+            .unwrap()
+            .order_id
+    }
+
+    async fn status(&self, order_id: i64) -> String {
+        self.service
+            .order_detail(OrderDetailRequest {
+                order_id,
+                ..Default::default()
+            })
+            .await
+            .unwrap()
+            .into_result()
+            .unwrap()
+            .status
+    }
+
+    async fn cancel(&self, order_id: i64, penalty_amount: Option<f64>) -> (bool, Option<f64>) {
+        let result = self
+            .service
+            .order_cancel(OrderCancelRequest {
+                order_id,
+                cancel_code: "行程变更".to_string(),
+                penalty_amount,
+                ..Default::default()
+            })
+            .await
+            .unwrap()
+            .into_result()
+            .unwrap();
+        (result.successs, result.penalty_amount)
+    }
+}
+
+#[tokio::test]
+async fn test_status_follows_schedule() {
+    let h = harness(|orders| orders).await;
+    let order_id = h.create("A-1", "SelfPay").await;
+    assert_eq!(h.status(order_id).await, STATUS_NEW);
+
+    h.clock.advance(chrono::Duration::seconds(31));
+    assert_eq!(h.status(order_id).await, STATUS_REVIEWED);
+    h.clock.advance(chrono::Duration::seconds(30));
+    assert_eq!(h.status(order_id).await, STATUS_CONFIRMED);
+
+    let incr = h
+        .service
+        .order_incr(IncrOrderRequest {
+            last_id: 0,
+            count: None,
+        })
+        .await
+        .unwrap()
+        .into_result()
+        .unwrap()
+        .orders
+        .unwrap();
+    let statuses: Vec<_> = incr.iter().map(|o| o.status.as_str()).collect();
+    assert_eq!(statuses, [STATUS_NEW, STATUS_REVIEWED, STATUS_CONFIRMED]);
+    assert_eq!(incr[1].time, "2025-06-20 10:00:30");
+
+    let next = h
+        .service
+        .order_incr(IncrOrderRequest {
+            last_id: incr[1].last_id,
+            count: None,
+        })
+        .await
+        .unwrap()
+        .into_result()
+        .unwrap()
+        .orders
+        .unwrap();
+    assert_eq!(next.len(), 1);
+    assert_eq!(next[0].order_id, order_id);
+}
+
+#[tokio::test]
+async fn test_create_detail_and_list_are_consistent() {
+    let h = harness(|orders| orders.first_order_id(500).room_name("0003", "高级大床房")).await;
+    let first = h.create("A-1", "SelfPay").await;
+    let second = h.create("A-2", "SelfPay").await;
+    assert_eq!((first, second), (500, 501));
+    assert_eq!(h.create("A-1", "SelfPay").await, first);
+
+    let detail = h
+        .service
+        .order_detail(OrderDetailRequest {
+            order_id: 0,
+            affiliate_confirmation_id: Some("A-2".to_string()),
+            options: None,
+        })
+        .await
+        .unwrap()
+        .into_result()
+        .unwrap();
+    assert_eq!(detail.order_id, second);
+    assert_eq!(detail.room_type_id, "0003");
+    assert_eq!(detail.room_name, "高级大床房");
+    assert_eq!(detail.cancel_time, "2025-06-22 18:00:00");
+    assert_eq!(detail.contact.name, "林立体");
+    assert_eq!(detail.order_rooms[0].customers[0].name, "林立体");
+
+    h.orders.set_status(first, STATUS_CHECKED_IN);
+    let list = h
+        .service
+        .order_list(OrderListRequest {
+            status: Some(STATUS_CHECKED_IN.to_string()),
+            page_index: 1,
+            ..Default::default()
+        })
+        .await
+        .unwrap()
+        .into_result()
+        .unwrap();
+    assert_eq!(list.count, 1);
+    assert_eq!(list.orders.unwrap()[0].order_id, first);
+    assert_eq!(h.orders.order_ids(), vec![first, second]);
+}
+
+#[tokio::test]
+async fn test_prepay_payment_flow() {
+    let h = harness(|orders| orders).await;
+    let created = h
+        .service
+        .order_create(order("P-1", "Prepay"))
+        .await
+        .unwrap()
+        .into_result()
+        .unwrap();
+    assert_eq!(
+        created.payment_deadline_time.as_deref(),
+        Some("2025-06-20 10:30:00")
+    );
+    let order_id = created.order_id;
+
+    let pay = |amount: f64, charged: bool| OrderPayRequest {
+        order_id,
+        is_guarantee_or_charged: charged,
+        amount,
+        ..Default::default()
+    };
+    let wrong = h.service.order_pay(pay(1.0, false)).await.unwrap();
+    assert!(!wrong.result.unwrap().is_success);
+    let paying = h.service.order_pay(pay(TOTAL_PRICE, false)).await.unwrap();
+    assert!(paying.result.unwrap().is_success);
+
+    let confirm = |sms_code: &str| OrderPayConfirmRequest {
+        order_id,
+        sms_code: sms_code.to_string(),
+        amount: TOTAL_PRICE,
+    };
+    let empty_code = h.service.order_pay_confirm(confirm("")).await.unwrap();
+    assert!(!empty_code.result.unwrap().is_success);
+    let confirmed = h
+        .service
+        .order_pay_confirm(confirm("123456"))
+        .await
+        .unwrap();
+    assert!(confirmed.result.unwrap().is_success);
+    let again = h.service.order_pay(pay(TOTAL_PRICE, true)).await.unwrap();
+    assert_eq!(again.result.unwrap().notes.as_deref(), Some("订单已支付"));
+
+    let incr = h
+        .service
+        .order_incr(IncrOrderRequest::default())
+        .await
+        .unwrap()
+        .into_result()
+        .unwrap()
+        .orders
+        .unwrap();
+    let pay_statuses: Vec<_> = incr.iter().map(|o| o.pay_status.unwrap()).collect();
+    assert_eq!(
+        pay_statuses,
+        [
+            PAY_STATUS_WAITING,
+            PAY_STATUS_PROCESSING,
+            PAY_STATUS_SUCCESS
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_free_cancel_refunds_payment() {
+    let h = harness(|orders| orders).await;
+    let order_id = h.create("P-1", "Prepay").await;
+    h.service
+        .order_pay(OrderPayRequest {
+            order_id,
+            is_guarantee_or_charged: true,
+            amount: TOTAL_PRICE,
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(h.cancel(order_id, Some(-1.0)).await, (true, Some(0.0)));
+    assert_eq!(h.status(order_id).await, STATUS_CANCELLED);
+    let detail = h
+        .service
+        .order_detail(OrderDetailRequest {
+            order_id,
+            ..Default::default()
+        })
+        .await
+        .unwrap()
+        .into_result()
+        .unwrap();
+    assert_eq!(detail.refund_amount, Some(TOTAL_PRICE));
+    assert_eq!(detail.is_cancelable, Some(false));
+
+    let err = h
+        .service
+        .order_cancel(OrderCancelRequest {
+            order_id,
+            ..Default::default()
+        })
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.api_error().unwrap().result_code(),
+        ElongResultCode::OrderNotCancelable
+    );
+}
+
+#[tokio::test]
+async fn test_late_cancel_penalty_rules() {
+    let h = harness(|orders| orders.late_cancel_penalty_rate(0.2)).await;
+    let order_id = h.create("A-1", "SelfPay").await;
+    h.clock
+        .advance(chrono::Duration::days(2) + chrono::Duration::hours(9));
+
+    assert_eq!(h.cancel(order_id, Some(-1.0)).await, (false, Some(56.92)));
+    assert_eq!(h.cancel(order_id, Some(100.0)).await, (false, Some(56.92)));
+    assert_eq!(h.status(order_id).await, STATUS_CONFIRMED);
+    assert_eq!(h.cancel(order_id, Some(56.92)).await, (true, Some(56.92)));
+    assert_eq!(h.status(order_id).await, STATUS_CANCELLED);
+}
+
+#[tokio::test]
+async fn test_commands_promote_and_errors() {
+    let h = harness(|orders| orders).await;
+    let order_id = h.create("A-1", "SelfPay").await;
+
+    let promoted = h
+        .service
+        .order_promote(OrderPromoteRequest { order_id })
+        .await
+        .unwrap()
+        .into_result()
+        .unwrap();
+    assert_eq!(promoted.adjust_time.as_deref(), Some("2025-06-20 10:00:00"));
+    assert_eq!(h.status(order_id).await, STATUS_REVIEWED);
+
+    assert!(h.orders.set_status(order_id, STATUS_CHECKED_IN));
+    assert!(!h.orders.set_status(1, STATUS_CHECKED_IN));
+    h.clock.advance(chrono::Duration::minutes(5));
+    assert_eq!(
+        h.orders.status(order_id).as_deref(),
+        Some(STATUS_CHECKED_IN)
+    );
+    let err = h
+        .service
+        .order_cancel(OrderCancelRequest {
+            order_id,
+            ..Default::default()
+        })
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ElongError::ApiError(_)));
+
+    let err = h
+        .service
+        .order_detail(OrderDetailRequest {
+            order_id: 1,
+            ..Default::default()
+        })
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.api_error().unwrap().result_code(),
+        ElongResultCode::OrderNotFound
+    );
+
+    let mut invalid = order("A-2", "SelfPay");
+    invalid.departure_date = "2025-06-22".to_string();
+    let err = h.service.order_create(invalid).await.unwrap_err();
+    assert_eq!(
+        err.api_error().unwrap().result_code(),
+        ElongResultCode::InvalidParameter
+    );
+}
+
+#[tokio::test]
+async fn test_registered_fixture_overrides_simulator() {
+    let h = harness(|orders| orders).await;
+    h.server.add_fixture(
+        "hotel.order.create",
+        MockResponse::api_error("H001022|房量不足"),
+    );
+    let err = h
+        .service
+        .order_create(order("A-1", "SelfPay"))
+        .await
+        .unwrap_err();
+    assert!(err.api_error().unwrap().is_inventory_unavailable());
+    assert!(h.orders.order_ids().is_empty());
+}
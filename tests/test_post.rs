@@ -0,0 +1,67 @@
+mod common;
+
+use std::sync::Arc;
+
+use common::{builder, RecordingTransport, INCR_ID_BODY};
+use elong_sdk::elong::{builder::ElongServiceBuilder, endpoints::ApiMethod, service::ElongService};
+use elong_sdk::network::transport::HttpMethod;
+use elong_sdk::request::incr_id::IncrIdRequest;
+use elong_sdk::Elong;
+use serde_json::json;
+
+fn service(
+    configure: impl FnOnce(ElongServiceBuilder) -> ElongServiceBuilder,
+) -> (ElongService, Arc<RecordingTransport>) {
+    let transport = RecordingTransport::body(INCR_ID_BODY);
+    (
+        configure(builder(transport.clone())).build().unwrap(),
+        transport,
+    )
+}
+
+fn large_request() -> serde_json::Value {
+    let nationalities: Vec<String> = (0..1000).map(|i| format!("N{i:04}")).collect();
+    json!({ "HotelIds": "90919291", "Nat": nationalities })
+}
+
+#[tokio::test]
+async fn test_get_by_default() {
+    let (service, transport) = service(|b| b);
+
+    service.get_incr_id(IncrIdRequest::default()).await.unwrap();
+    assert_eq!(transport.http_methods(), vec![HttpMethod::Get]);
+}
+
+#[tokio::test]
+async fn test_post_above_threshold() {
+    let (service, transport) = service(|b| b);
+
+    service
+        .call_raw("hotel.detail", large_request())
+        .await
+        .unwrap();
+    assert_eq!(transport.http_methods(), vec![HttpMethod::Post]);
+}
+
+#[tokio::test]
+async fn test_threshold_disabled() {
+    let (service, transport) = service(|b| b.post_threshold(None));
+
+    service
+        .call_raw("hotel.detail", large_request())
+        .await
+        .unwrap();
+    assert_eq!(transport.http_methods(), vec![HttpMethod::Get]);
+}
+
+#[tokio::test]
+async fn test_post_per_method() {
+    let (service, transport) = service(|b| b.post_method(ApiMethod::IncrId));
+
+    service.get_incr_id(IncrIdRequest::default()).await.unwrap();
+    service.call_raw("hotel.incr.inv", json!({})).await.unwrap();
+    assert_eq!(
+        transport.http_methods(),
+        vec![HttpMethod::Post, HttpMethod::Get]
+    );
+}
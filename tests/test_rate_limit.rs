@@ -0,0 +1,101 @@
+mod common;
+
+use std::time::Duration;
+
+use common::{builder, RecordingTransport, INCR_ID_BODY};
+use elong_sdk::elong::{
+    endpoints::ApiMethod,
+    rate_limit::{Quota, RateLimitConfig, MIN_PER_SECOND},
+};
+use elong_sdk::request::incr_id::IncrIdRequest;
+use elong_sdk::Elong;
+use tokio::time::Instant;
+
+#[tokio::test(start_paused = true)]
+async fn test_rate_limit_shared_across_clones() {
+    let service = builder(RecordingTransport::body(INCR_ID_BODY))
+        .rate_limit(RateLimitConfig::new().method(ApiMethod::IncrId, Quota::per_second(10.0)))
+        .build()
+        .unwrap();
+
+    let start = Instant::now();
+    let mut handles = Vec::new();
+    for _ in 0..30 {
+        let service = service.clone();
+        handles.push(tokio::spawn(async move {
+            service.get_incr_id(IncrIdRequest::default()).await
+        }));
+    }
+    for handle in handles {
+        assert!(handle.await.unwrap().is_ok());
+    }
+
+    // 突发 10 个，剩余 20 个按 10 QPS 放行
+    let elapsed = start.elapsed().as_secs_f64();
+    assert!((1.9..2.5).contains(&elapsed), "elapsed: {elapsed}");
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_unlimited_method() {
+    let service = builder(RecordingTransport::body(INCR_ID_BODY))
+        .rate_limit(RateLimitConfig::new().method(ApiMethod::StaticInfo, Quota::per_second(1.0)))
+        .build()
+        .unwrap();
+
+    let start = Instant::now();
+    for _ in 0..20 {
+        assert!(service.get_incr_id(IncrIdRequest::default()).await.is_ok());
+    }
+    assert!(start.elapsed().as_millis() < 10);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_cancelled_wait_returns_token() {
+    let service = builder(RecordingTransport::body(INCR_ID_BODY))
+        .rate_limit(RateLimitConfig::new().method(ApiMethod::IncrId, Quota::per_second(1.0)))
+        .build()
+        .unwrap();
+
+    let start = Instant::now();
+    service.get_incr_id(IncrIdRequest::default()).await.unwrap();
+    // 等待中被取消，预扣的令牌应归还
+    let cancelled = tokio::time::timeout(
+        Duration::from_millis(100),
+        service.get_incr_id(IncrIdRequest::default()),
+    )
+    .await;
+    assert!(cancelled.is_err());
+
+    service.get_incr_id(IncrIdRequest::default()).await.unwrap();
+    let elapsed = start.elapsed().as_secs_f64();
+    assert!((0.9..1.5).contains(&elapsed), "elapsed: {elapsed}");
+}
+
+#[test]
+fn test_quota_clamped() {
+    assert_eq!(Quota::per_second(0.0).per_second, MIN_PER_SECOND);
+    assert_eq!(Quota::per_second(-5.0).per_second, MIN_PER_SECOND);
+    assert_eq!(Quota::per_second(f64::NAN).per_second, MIN_PER_SECOND);
+    assert_eq!(Quota::per_second(0.0).burst, 1);
+    assert_eq!(Quota::per_second(2.5).burst, 3);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_literal_zero_quota() {
+    let service = builder(RecordingTransport::body(INCR_ID_BODY))
+        .rate_limit(RateLimitConfig::new().method(
+            ApiMethod::IncrId,
+            Quota {
+                per_second: 0.0,
+                burst: 0,
+            },
+        ))
+        .build()
+        .unwrap();
+
+    let start = Instant::now();
+    service.get_incr_id(IncrIdRequest::default()).await.unwrap();
+    service.get_incr_id(IncrIdRequest::default()).await.unwrap();
+    // 按最小速率放行，不会 panic
+    assert!(start.elapsed() >= Duration::from_secs_f64(1.0 / MIN_PER_SECOND));
+}
@@ -0,0 +1,190 @@
+mod common;
+
+use std::sync::Mutex;
+
+use common::{builder, RecordingTransport, TEST_KEY, TEST_SECRET};
+use elong_sdk::elong::redact::redact_json;
+use elong_sdk::request::order_addinvoice::DeliveryAddress;
+use elong_sdk::request::order_create::{
+    Contact, CreditCard, Invoice, OrderCreateRequest, Recipient,
+};
+use elong_sdk::request::order_feedback::OrderFeedbackRequest;
+use elong_sdk::request::order_list::OrderListRequest;
+use elong_sdk::Elong;
+use log::{Level, LevelFilter, Log, Metadata, Record};
+
+struct CaptureLogger {
+    lines: Mutex<Vec<String>>,
+}
+
+impl Log for CaptureLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= Level::Debug
+    }
+
+    fn log(&self, record: &Record) {
+        self.lines.lock().unwrap().push(record.args().to_string());
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: CaptureLogger = CaptureLogger {
+    lines: Mutex::new(Vec::new()),
+};
+
+#[tokio::test]
+async fn test_secret_never_logged() {
+    log::set_logger(&LOGGER).unwrap();
+    log::set_max_level(LevelFilter::Debug);
+
+    let service = builder(RecordingTransport::body(
+        r#"{"Code":"0","Result":{"OrderId":1,"Contact":{"Mobile":"13800138000"}},"Guid":"g"}"#,
+    ))
+    .log_payloads(true)
+    .build()
+    .unwrap();
+
+    let request = OrderCreateRequest {
+        contact: Contact {
+            name: "王小明".to_string(),
+            mobile: "13800138000".to_string(),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let _ = service.order_create(request).await;
+
+    let lines = LOGGER.lines.lock().unwrap();
+    assert!(lines
+        .iter()
+        .any(|line| line.contains("hotel.order.create request")));
+    for line in lines.iter() {
+        assert!(!line.contains(TEST_SECRET), "{line}");
+        assert!(!line.contains(TEST_KEY), "{line}");
+        assert!(!line.contains("13800138000"), "{line}");
+        assert!(!line.contains("王小明"), "{line}");
+    }
+}
+
+#[test]
+fn test_redact_json() {
+    let json = r#"{"Contact":{"Name":"王小明","Mobile":"13800138000","Gender":"Male"},"CreditCard":{"Number":"6222","Cvv":"123"},"HotelName":"如家"}"#;
+    let redacted = redact_json(json);
+    assert!(!redacted.contains("13800138000"));
+    assert!(!redacted.contains("王小明"));
+    assert!(!redacted.contains("6222"));
+    assert!(redacted.contains("Male"));
+    assert!(redacted.contains("如家"));
+    assert_eq!(redact_json("<html>"), "<6 bytes non-JSON body>");
+}
+
+#[test]
+fn test_redact_json_matches_debug() {
+    let recipient = Recipient {
+        province: "北京".to_string(),
+        street: "长安街1号".to_string(),
+        name: "王小明".to_string(),
+        phone: "13800138000".to_string(),
+        ..Default::default()
+    };
+    let address = DeliveryAddress {
+        street: Some("建国路88号".to_string()),
+        recipient_name: Some("李四".to_string()),
+        post_email: Some("li@example.com".to_string()),
+        phone: "13900139000".to_string(),
+        ..Default::default()
+    };
+    let json = format!(
+        r#"{{"Recipient":{},"DeliveryAddress":{}}}"#,
+        serde_json::to_string(&recipient).unwrap(),
+        serde_json::to_string(&address).unwrap()
+    );
+    let redacted = redact_json(&json);
+    let debug = format!("{recipient:?} {address:?}");
+    for value in [
+        "长安街1号",
+        "王小明",
+        "13800138000",
+        "建国路88号",
+        "李四",
+        "li@example.com",
+        "13900139000",
+    ] {
+        assert!(!redacted.contains(value), "{value} in {redacted}");
+        assert!(!debug.contains(value), "{value} in {debug}");
+    }
+    assert!(redacted.contains("北京"));
+}
+
+#[test]
+fn test_debug_masks_sensitive_fields() {
+    let contact = Contact {
+        name: "王小明".to_string(),
+        mobile: "13800138000".to_string(),
+        email: Some("wang@example.com".to_string()),
+        ..Default::default()
+    };
+    let card = CreditCard {
+        number: "6222021234567890".to_string(),
+        cvv: "123".to_string(),
+        ..Default::default()
+    };
+    let debug = format!("{contact:?} {card:?}");
+    assert!(!debug.contains("13800138000"));
+    assert!(!debug.contains("wang@example.com"));
+    assert!(!debug.contains("6222021234567890"));
+    assert!(!debug.contains("\"123\""));
+}
+
+#[test]
+fn test_redact_keeps_place_names() {
+    let json = r#"{"Hotel":{"Name":"如家"},"City":{"Name":"北京"},"Rooms":[{"Name":"大床房"}],"OrderRooms":[{"Customers":[{"Name":"王小明"}]}],"CustomerName":"李四"}"#;
+    let redacted = redact_json(json);
+    assert!(redacted.contains("如家"));
+    assert!(redacted.contains("北京"));
+    assert!(redacted.contains("大床房"));
+    assert!(!redacted.contains("王小明"));
+    assert!(!redacted.contains("李四"));
+}
+
+#[test]
+fn test_order_queries_mask_personal_fields() {
+    let list = OrderListRequest {
+        hotel_id: Some("90000001".to_string()),
+        mobile: Some("13800138000".to_string()),
+        customer_name: Some("王小明".to_string()),
+        ..Default::default()
+    };
+    let feedback = OrderFeedbackRequest {
+        order_id: 1,
+        customer_name: Some("李四光".to_string()),
+        ..Default::default()
+    };
+    let invoice = Invoice {
+        register_bank_num: Some("6222021234567890".to_string()),
+        register_address: Some("建国路88号".to_string()),
+        register_phone_num: Some("01088888888".to_string()),
+        ..Default::default()
+    };
+    let json = format!(
+        "[{},{},{}]",
+        serde_json::to_string(&list).unwrap(),
+        serde_json::to_string(&feedback).unwrap(),
+        serde_json::to_string(&invoice).unwrap()
+    );
+    let redacted = redact_json(&json);
+    let debug = format!("{list:?} {feedback:?} {invoice:?}");
+    for value in [
+        "13800138000",
+        "王小明",
+        "李四光",
+        "6222021234567890",
+        "建国路88号",
+        "01088888888",
+    ] {
+        assert!(!redacted.contains(value), "{value} in {redacted}");
+        assert!(!debug.contains(value), "{value} in {debug}");
+    }
+    assert!(debug.contains("90000001"));
+}
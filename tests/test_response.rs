@@ -0,0 +1,51 @@
+use elong_sdk::elong::{endpoints::ApiMethod, error::ElongError};
+use elong_sdk::response::api_response::{ElongResponse, ElongResultExt};
+
+fn response(code: &str, result: Option<u32>) -> ElongResponse<u32> {
+    ElongResponse {
+        code: code.to_string(),
+        result,
+        guid: Some("guid".to_string()),
+    }
+}
+
+#[test]
+fn test_into_result() {
+    assert_eq!(response("0", Some(1)).into_result().unwrap(), 1);
+
+    let err = response("0", None).into_result().unwrap_err();
+    assert!(matches!(err, ElongError::MissingResult(Some(ref guid)) if guid == "guid"));
+
+    let err = response("H000997|系统繁忙", None)
+        .into_result()
+        .unwrap_err();
+    assert_eq!(err.api_error().unwrap().code, "H000997");
+    assert_eq!(
+        err.api_error().unwrap().to_string(),
+        "H000997 系统繁忙 (guid: guid)"
+    );
+}
+
+#[test]
+fn test_into_result_for_method() {
+    let err = response("H000997|系统繁忙", None)
+        .into_result_for(ApiMethod::IncrId)
+        .unwrap_err();
+    assert_eq!(err.api_error().unwrap().method, "hotel.incr.id");
+    assert_eq!(
+        err.api_error().unwrap().to_string(),
+        "[hotel.incr.id] H000997 系统繁忙 (guid: guid)"
+    );
+}
+
+#[test]
+fn test_into_data() {
+    let result: Result<ElongResponse<u32>, ElongError> = Ok(response("0", Some(2)));
+    assert_eq!(result.into_data().unwrap(), 2);
+
+    let result: Result<ElongResponse<u32>, ElongError> = Err(ElongError::HttpStatus(502));
+    assert!(matches!(
+        result.into_data(),
+        Err(ElongError::HttpStatus(502))
+    ));
+}
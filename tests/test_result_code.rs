@@ -0,0 +1,73 @@
+use elong_sdk::elong::result_code::{ElongResultCode, SuggestedAction};
+use elong_sdk::response::api_response::ElongResponse;
+
+#[test]
+fn test_parse() {
+    assert_eq!(ElongResultCode::parse("0"), ElongResultCode::Success);
+    assert_eq!(
+        ElongResultCode::parse("H000997|系统繁忙"),
+        ElongResultCode::SystemBusy
+    );
+    assert_eq!(
+        ElongResultCode::parse("H000002|时间戳过期"),
+        ElongResultCode::TimestampExpired
+    );
+    assert_eq!(
+        ElongResultCode::parse("H001022|房量不足"),
+        ElongResultCode::InventoryUnavailable
+    );
+    assert_eq!(
+        ElongResultCode::parse("H009999"),
+        ElongResultCode::Unknown("H009999".to_string())
+    );
+}
+
+#[test]
+fn test_parse_by_message() {
+    assert_eq!(
+        ElongResultCode::parse("H000997"),
+        ElongResultCode::Unknown("H000997".to_string())
+    );
+    assert_eq!(
+        ElongResultCode::parse("H000004|请求超时"),
+        ElongResultCode::SystemError
+    );
+    assert_eq!(
+        ElongResultCode::parse("H009998|订单已超时不可取消"),
+        ElongResultCode::OrderNotCancelable
+    );
+}
+
+#[test]
+fn test_suggested_action() {
+    assert_eq!(ElongResultCode::Success.suggested_action(), None);
+    assert_eq!(
+        ElongResultCode::SystemBusy.suggested_action(),
+        Some(SuggestedAction::Retry)
+    );
+    assert_eq!(
+        ElongResultCode::PriceChanged.suggested_action(),
+        Some(SuggestedAction::Requote)
+    );
+    assert_eq!(
+        ElongResultCode::SignatureInvalid.suggested_action(),
+        Some(SuggestedAction::Abort)
+    );
+}
+
+#[test]
+fn test_description() {
+    let code = ElongResultCode::InventoryUnavailable;
+    assert_eq!(code.description("zh-CN"), code.description_zh());
+    assert_eq!(code.description("en-US"), code.description_en());
+}
+
+#[test]
+fn test_response_result_code() {
+    let response: ElongResponse<()> = ElongResponse {
+        code: "H000997|系统繁忙".to_string(),
+        result: None,
+        guid: None,
+    };
+    assert_eq!(response.result_code(), ElongResultCode::SystemBusy);
+}
@@ -0,0 +1,91 @@
+mod common;
+
+use std::{io, sync::Arc, time::Duration};
+
+use common::{builder, RecordingTransport};
+use elong_sdk::elong::{
+    endpoints::ApiMethod, error::ElongError, retry::RetryPolicy, service::ElongService,
+};
+use elong_sdk::network::transport::TransportResponse;
+use elong_sdk::request::incr_inv::IncrInvRequest;
+use elong_sdk::request::order_create::OrderCreateRequest;
+use elong_sdk::Elong;
+
+const INCR_INV_BODY: &str = r#"{"Code":"0","Result":{"Inventories":[]},"Guid":"g"}"#;
+
+/// 前 `failures` 次返回指定错误，之后返回成功响应
+fn flaky(failures: usize, error: fn() -> ElongError) -> Arc<RecordingTransport> {
+    RecordingTransport::new(move |call, _| {
+        if call < failures {
+            return Err(error());
+        }
+        Ok(TransportResponse::new(INCR_INV_BODY))
+    })
+}
+
+fn service(transport: Arc<RecordingTransport>, retry: RetryPolicy) -> ElongService {
+    builder(transport).retry_policy(retry).build().unwrap()
+}
+
+fn fast_policy() -> RetryPolicy {
+    RetryPolicy::default()
+        .max_attempts(3)
+        .backoff(Duration::from_millis(1), Duration::from_millis(5))
+}
+
+#[tokio::test]
+async fn test_retry_transient_error() {
+    let transport = flaky(2, || ElongError::HttpStatus(502));
+    let service = service(transport.clone(), fast_policy());
+
+    let result = service.get_incr_inv(IncrInvRequest::default()).await;
+    assert!(result.is_ok());
+    assert_eq!(transport.calls(), 3);
+}
+
+#[tokio::test]
+async fn test_retry_gives_up() {
+    let transport = flaky(5, || {
+        io::Error::new(io::ErrorKind::UnexpectedEof, "truncated gzip").into()
+    });
+    let service = service(transport.clone(), fast_policy());
+
+    let result = service.get_incr_inv(IncrInvRequest::default()).await;
+    assert!(result.is_err());
+    assert_eq!(transport.calls(), 3);
+}
+
+#[tokio::test]
+async fn test_no_retry_on_client_error() {
+    let transport = flaky(1, || ElongError::HttpStatus(400));
+    let service = service(transport.clone(), fast_policy());
+
+    let result = service.get_incr_inv(IncrInvRequest::default()).await;
+    assert!(result.is_err());
+    assert_eq!(transport.calls(), 1);
+}
+
+#[tokio::test]
+async fn test_no_retry_for_order_create() {
+    let transport = flaky(1, || ElongError::HttpStatus(502));
+    let service = service(transport.clone(), fast_policy());
+
+    let result = service.order_create(OrderCreateRequest::default()).await;
+    assert!(result.is_err());
+    assert_eq!(transport.calls(), 1);
+}
+
+#[test]
+fn test_retry_policy() {
+    let policy = RetryPolicy::default().retry_method(ApiMethod::IncrInv, false);
+    assert!(!policy.is_enabled_for(ApiMethod::IncrInv));
+    assert!(policy.is_enabled_for(ApiMethod::StaticInfo));
+    assert!(!policy.is_enabled_for(ApiMethod::OrderPay));
+
+    let policy = RetryPolicy::default()
+        .jitter(false)
+        .backoff(Duration::from_millis(100), Duration::from_millis(300));
+    assert_eq!(policy.backoff_for(1), Duration::from_millis(100));
+    assert_eq!(policy.backoff_for(2), Duration::from_millis(200));
+    assert_eq!(policy.backoff_for(3), Duration::from_millis(300));
+}
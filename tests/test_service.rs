@@ -34,7 +34,7 @@ use elong_sdk::request::static_list::StaticListRequest;
 use elong_sdk::Elong;
 
 fn create_test_service() -> ElongService {
-    let mut service = ElongService::new();
+    let mut service = ElongService::builder().build().unwrap();
     service.url = ApiEndpoint::Prod.url();
     service
 }
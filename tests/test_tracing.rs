@@ -0,0 +1,85 @@
+#![cfg(feature = "tracing")]
+
+mod common;
+
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{Arc, Mutex},
+};
+
+use common::{builder, RecordingTransport};
+use elong_sdk::elong::retry::RetryPolicy;
+use elong_sdk::network::transport::TransportResponse;
+use elong_sdk::request::incr_id::IncrIdRequest;
+use elong_sdk::Elong;
+use tracing::{
+    field::{Field, Visit},
+    span::{Attributes, Id, Record},
+    Subscriber,
+};
+use tracing_subscriber::{layer::Context, prelude::*, Layer};
+
+type Fields = Arc<Mutex<HashMap<String, String>>>;
+
+/// 收集 `elong.call` span 的字段
+struct CaptureLayer {
+    fields: Fields,
+}
+
+struct FieldVisitor<'a>(&'a mut HashMap<String, String>);
+
+impl Visit for FieldVisitor<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.0.insert(
+            field.name().to_string(),
+            format!("{value:?}").trim_matches('"').to_string(),
+        );
+    }
+}
+
+impl<S: Subscriber> Layer<S> for CaptureLayer {
+    fn on_new_span(&self, attrs: &Attributes<'_>, _id: &Id, _ctx: Context<'_, S>) {
+        if attrs.metadata().name() == "elong.call" {
+            attrs.record(&mut FieldVisitor(&mut self.fields.lock().unwrap()));
+        }
+    }
+
+    fn on_record(&self, _id: &Id, values: &Record<'_>, _ctx: Context<'_, S>) {
+        values.record(&mut FieldVisitor(&mut self.fields.lock().unwrap()));
+    }
+}
+
+#[tokio::test]
+async fn test_call_span_fields() {
+    let fields = Fields::default();
+    let subscriber = tracing_subscriber::registry().with(CaptureLayer {
+        fields: fields.clone(),
+    });
+    let _guard = tracing::subscriber::set_default(subscriber);
+
+    // 第一次返回系统繁忙，之后返回成功
+    let transport = RecordingTransport::new(|call, _| {
+        let body = if call == 0 {
+            r#"{"Code":"H000997|系统繁忙","Guid":"busy"}"#
+        } else {
+            r#"{"Code":"0","Result":{"LastId":1},"Guid":"guid-1"}"#
+        };
+        Ok(TransportResponse::new(body).with_status(200))
+    });
+    let service = builder(transport)
+        .retry_policy(RetryPolicy::default().backoff(Default::default(), Default::default()))
+        .build()
+        .unwrap();
+    service.get_incr_id(IncrIdRequest::default()).await.unwrap();
+
+    let fields = fields.lock().unwrap();
+    assert_eq!(fields["method"], "hotel.incr.id");
+    assert!(fields["request_size"].parse::<usize>().unwrap() > 0);
+    assert_eq!(fields["http_status"], "200");
+    assert_eq!(fields["code"], "0");
+    assert_eq!(fields["guid"], "guid-1");
+    assert_eq!(fields["retries"], "1");
+    assert!(fields.contains_key("elapsed_ms"));
+    assert!(!fields.contains_key("error"));
+}
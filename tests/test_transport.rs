@@ -0,0 +1,34 @@
+mod common;
+
+use common::{RecordingTransport, TEST_KEY, TEST_SECRET, TEST_USER};
+use elong_sdk::elong::{endpoints::ApiEndpoint, service::ElongService};
+use elong_sdk::request::static_city::StaticCityRequest;
+use elong_sdk::Elong;
+
+#[tokio::test]
+async fn test_custom_transport() {
+    let transport = RecordingTransport::body(
+        r#"{"Code":"0","Result":{"Count":0,"Citys":[]},"Guid":"test-guid"}"#,
+    );
+    let service = ElongService::new_with_transport(
+        TEST_USER.to_string(),
+        TEST_KEY.to_string(),
+        TEST_SECRET.to_string(),
+        ApiEndpoint::Test,
+        transport.clone(),
+    );
+
+    let request = StaticCityRequest {
+        country_type: Some(1),
+        ..Default::default()
+    };
+    let result = service.get_static_city(request).await.unwrap();
+    assert!(result.is_success());
+    assert_eq!(result.guid.as_deref(), Some("test-guid"));
+
+    let calls = transport.requests();
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].url, ApiEndpoint::Test.url());
+    assert_eq!(calls[0].method, "hotel.static.city");
+    assert!(calls[0].data.contains(r#""CountryType":1"#));
+}